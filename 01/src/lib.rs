@@ -0,0 +1,450 @@
+use aoc_common::{Generator, Param, Params, ParseError, Rng, Solution};
+use std::collections::BTreeMap;
+use std::io::{self, Read};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const NAME: &'static str = "not_quite_lisp";
+
+    const PARAMS: &'static [Param] = &[
+        Param::new("lenient", 0, "Skip whitespace and comments from '#' to the end of the line").range(0, 1),
+    ];
+
+    type Input = Instructions;
+    type Answer1 = i32;
+    type Answer2 = Option<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, ParseError> {
+        let syntax = if params.get("lenient") == 0 { Syntax::Strict } else { Syntax::Lenient };
+        parse_input_with(input, syntax)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        count_floors(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        trigger_basement(input)
+    }
+}
+
+impl Generator for Day01 {
+    const DEFAULT_SIZE: usize = 7000;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        rng.string_from("()", size)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Syntax {
+    // Nothing but '(' and ')'
+    #[default]
+    Strict,
+    // Also whitespace and comments from '#' to the end of the line, e.g. a trailing newline
+    Lenient,
+}
+
+// An input byte that is not an instruction. In streams, bytes that are not ASCII show up as U+FFFD.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InstructionError {
+    pub offset: u64,
+    pub found: char,
+}
+
+impl std::fmt::Display for InstructionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid character '{}' at byte {}", self.found.escape_default(), self.offset)
+    }
+}
+
+impl std::error::Error for InstructionError {}
+
+// Change of floor for an instruction
+fn floor_change(c: char) -> Option<i32> {
+    match c {
+        '(' => Some(1),
+        ')' => Some(-1),
+        _ => None,
+    }
+}
+
+// Input with nothing but '(' and ')', only the parser makes these
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instructions(String);
+
+impl Instructions {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn floor_changes(&self) -> impl Iterator<Item = i32> + '_ {
+        self.0.bytes().map(|b| if b == b'(' { 1 } else { -1 })
+    }
+}
+
+enum Symbol {
+    Instruction(i32),
+    Skipped,
+    Invalid,
+}
+
+// Shared by the parser and the tracker so both accept the same inputs. Only ASCII whitespace is skipped, as
+// the tracker sees bytes and not characters. `in_comment` carries over from one character to the next.
+fn symbol(c: char, syntax: Syntax, in_comment: &mut bool) -> Symbol {
+    if *in_comment {
+        *in_comment = c != '\n';
+        return Symbol::Skipped;
+    }
+    match floor_change(c) {
+        Some(change) => Symbol::Instruction(change),
+        None if syntax == Syntax::Lenient && c == '#' => {
+            *in_comment = true;
+            Symbol::Skipped
+        },
+        None if syntax == Syntax::Lenient && c.is_ascii() && c.is_whitespace() => Symbol::Skipped,
+        None => Symbol::Invalid,
+    }
+}
+
+// Only the instructions of `input`, in the order they appear
+pub fn parse_instructions(input: &str, syntax: Syntax) -> Result<Instructions, InstructionError> {
+    let mut instructions = String::with_capacity(input.len());
+    let mut in_comment = false;
+    for (i, c) in input.char_indices() {
+        match symbol(c, syntax, &mut in_comment) {
+            Symbol::Instruction(_) => instructions.push(c),
+            Symbol::Skipped => {},
+            Symbol::Invalid => return Err(InstructionError { offset: i as u64, found: c }),
+        }
+    }
+    Ok(Instructions(instructions))
+}
+
+pub fn parse_input(input: &str) -> Result<Instructions, ParseError> {
+    parse_input_with(input, Syntax::Strict)
+}
+
+pub fn parse_input_with(input: &str, syntax: Syntax) -> Result<Instructions, ParseError> {
+    parse_instructions(input, syntax).map_err(|InstructionError { offset, found }| {
+        ParseError::at_offset(input, offset as usize, format!("Invalid character '{}'", found.escape_default()))
+    })
+}
+
+pub fn count_floors(instructions: &Instructions) -> i32 {
+    instructions.floor_changes().sum()
+}
+
+// Position of the instruction that first enters the basement, counting from 1
+pub fn trigger_basement(instructions: &Instructions) -> Option<usize> {
+    first_visit(instructions, -1)
+}
+
+// Where Santa is after each instruction as (position, floor), starting with (0, 0) before the first one
+pub fn floor_trace(instructions: &Instructions) -> impl Iterator<Item = (usize, i32)> + '_ {
+    let steps = instructions.floor_changes().scan(0, |floor, change| {
+        *floor += change;
+        Some(*floor)
+    });
+    std::iter::once(0).chain(steps).enumerate()
+}
+
+pub fn first_visit(instructions: &Instructions, floor: i32) -> Option<usize> {
+    floor_trace(instructions).find(|&(_, f)| f == floor).map(|(i, _)| i)
+}
+
+// Positions where Santa comes back to `floor` after the first visit
+pub fn return_positions(instructions: &Instructions, floor: i32) -> impl Iterator<Item = usize> + '_ {
+    floor_trace(instructions).filter(move |&(_, f)| f == floor).map(|(i, _)| i).skip(1)
+}
+
+// Lowest and highest floor reached, the ground floor counts as Santa starts there
+pub fn floor_range(instructions: &Instructions) -> (i32, i32) {
+    floor_trace(instructions).fold((0, 0), |(low, high), (_, f)| (low.min(f), high.max(f)))
+}
+
+// Number of positions of the trace spent on each floor, adds up to the number of instructions plus one
+pub fn time_per_floor(instructions: &Instructions) -> BTreeMap<i32, usize> {
+    let mut times = BTreeMap::new();
+    for (_, floor) in floor_trace(instructions) {
+        *times.entry(floor).or_insert(0) += 1;
+    }
+    times
+}
+
+// Follows the instructions a chunk at a time, so inputs of any size need no more memory than the chunk.
+// Floors and positions are 64 bit as such inputs can be longer than an i32.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FloorTracker {
+    syntax: Syntax,
+    floor: i64,
+    position: u64,
+    offset: u64,
+    in_comment: bool,
+    basement: Option<u64>,
+}
+
+impl FloorTracker {
+    pub fn new() -> FloorTracker {
+        FloorTracker::default()
+    }
+
+    pub fn with_syntax(mut self, syntax: Syntax) -> FloorTracker {
+        self.syntax = syntax;
+        self
+    }
+
+    pub fn floor(&self) -> i64 {
+        self.floor
+    }
+
+    // Number of instructions followed so far
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    // Position of the instruction that first enters the basement, counting from 1
+    pub fn basement(&self) -> Option<u64> {
+        self.basement
+    }
+
+    // The instructions before an invalid byte are followed, the error tells its offset in the whole stream
+    pub fn feed(&mut self, chunk: &[u8]) -> Result<(), InstructionError> {
+        let mut rest = chunk;
+        if self.basement.is_none() {
+            while let Some((&b, tail)) = rest.split_first() {
+                self.step(b)?;
+                rest = tail;
+                if self.floor == -1 {
+                    self.basement = Some(self.position);
+                    break;
+                }
+            }
+        }
+        // Once in the basement only the floor is left to find, which does not need a check per byte
+        let up = rest.iter().filter(|&&b| b == b'(').count();
+        let down = rest.iter().filter(|&&b| b == b')').count();
+        if up + down == rest.len() && !self.in_comment {
+            self.floor += up as i64 - down as i64;
+            self.position += rest.len() as u64;
+            self.offset += rest.len() as u64;
+            return Ok(());
+        }
+        rest.iter().try_for_each(|&b| self.step(b))
+    }
+
+    fn step(&mut self, b: u8) -> Result<(), InstructionError> {
+        let c = if b.is_ascii() { char::from(b) } else { char::REPLACEMENT_CHARACTER };
+        match symbol(c, self.syntax, &mut self.in_comment) {
+            Symbol::Instruction(change) => {
+                self.floor += change as i64;
+                self.position += 1;
+            },
+            Symbol::Skipped => {},
+            Symbol::Invalid => return Err(InstructionError { offset: self.offset, found: c }),
+        }
+        self.offset += 1;
+        Ok(())
+    }
+}
+
+const CHUNK_SIZE: usize = 64 << 10;
+
+// Final floor and first basement position in one pass over `reader`
+pub fn track_floors(mut reader: impl Read, syntax: Syntax) -> io::Result<FloorTracker> {
+    let mut tracker = FloorTracker::new().with_syntax(syntax);
+    let mut buffer = vec![0; CHUNK_SIZE];
+    loop {
+        match reader.read(&mut buffer) {
+            Ok(0) => return Ok(tracker),
+            Ok(n) => tracker.feed(&buffer[..n]).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {},
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        count_floors, first_visit, floor_range, floor_trace, parse_input, parse_instructions, return_positions,
+        time_per_floor, track_floors, trigger_basement, Day01, FloorTracker, InstructionError, Instructions, Syntax,
+    };
+    use aoc_common::{Generator, Params, Rng, Solution};
+    use std::collections::BTreeMap;
+    use std::io::{self, Read};
+
+    fn parsed(input: &str) -> Instructions {
+        parse_instructions(input, Syntax::Strict).unwrap()
+    }
+
+    #[test]
+    fn test_count_floor() {
+        assert_eq!(count_floors(&parsed("")), 0);
+
+        assert_eq!(count_floors(&parsed("(())")), 0);
+        assert_eq!(count_floors(&parsed("()()")), 0);
+        
+        assert_eq!(count_floors(&parsed("(((")), 3);
+        assert_eq!(count_floors(&parsed("(()(()(")), 3);
+        assert_eq!(count_floors(&parsed("))(((((")), 3);
+        
+        assert_eq!(count_floors(&parsed("())")), -1);
+        assert_eq!(count_floors(&parsed("))(")), -1);
+        
+        assert_eq!(count_floors(&parsed(")))")), -3);
+        assert_eq!(count_floors(&parsed(")())())")), -3);
+    }
+
+    #[test]
+    fn test_trigger_basement() {
+        assert_eq!(trigger_basement(&parsed("")), None);
+        assert_eq!(trigger_basement(&parsed("(((")), None);
+        assert_eq!(trigger_basement(&parsed(")")), Some(1));
+        assert_eq!(trigger_basement(&parsed("()())")), Some(5));
+    }
+
+    #[test]
+    fn test_floor_trace() {
+        assert_eq!(floor_trace(&parsed("")).collect::<Vec<_>>(), vec![(0, 0)]);
+        assert_eq!(floor_trace(&parsed("(()))")).collect::<Vec<_>>(), vec![(0, 0), (1, 1), (2, 2), (3, 1), (4, 0), (5, -1)]);
+
+        let input = &parsed("(()))((()");
+        assert_eq!(first_visit(input, 0), Some(0));
+        assert_eq!(first_visit(input, 2), Some(2));
+        assert_eq!(first_visit(input, -1), Some(5));
+        assert_eq!(first_visit(input, 3), None);
+        assert_eq!(return_positions(input, 0).collect::<Vec<_>>(), vec![4, 6]);
+        assert_eq!(return_positions(input, 2).collect::<Vec<_>>(), vec![8]);
+        assert_eq!(return_positions(input, -1).count(), 0);
+        assert_eq!(floor_range(input), (-1, 2));
+        assert_eq!(floor_range(&parsed("(((")), (0, 3));
+        assert_eq!(time_per_floor(input), BTreeMap::from([(-1, 1), (0, 3), (1, 4), (2, 2)]));
+    }
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(parse_input("(()))").unwrap().as_str(), "(()))");
+        let e = parse_input("(()\n)x").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 4));
+        let e = parse_input("()x").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 3));
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = Day01::generate(&mut Rng::new(seed), 200);
+            assert!(Day01::parse(&input).is_ok(), "{}", input);
+        }
+    }
+
+    // Hands out at most `size` bytes per read, to split the input at every possible place
+    struct Trickle<'a>(&'a [u8], usize);
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let n = self.1.min(buf.len()).min(self.0.len());
+            buf[..n].copy_from_slice(&self.0[..n]);
+            self.0 = &self.0[n..];
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn test_track_floors() {
+        let tracker = track_floors("()())((".as_bytes(), Syntax::Strict).unwrap();
+        assert_eq!((tracker.floor(), tracker.position(), tracker.basement()), (1, 7, Some(5)));
+        assert_eq!(track_floors("(((".as_bytes(), Syntax::Strict).unwrap().basement(), None);
+        assert_eq!(track_floors(io::empty(), Syntax::Lenient).unwrap(), FloorTracker::new().with_syntax(Syntax::Lenient));
+
+        for seed in 0..20 {
+            let input = Day01::generate(&mut Rng::new(seed), 500);
+            let instructions = parsed(&input);
+            let basement = trigger_basement(&instructions).map(|p| p as u64);
+            for size in [1, 3, 64, 1000] {
+                let tracker = track_floors(Trickle(input.as_bytes(), size), Syntax::Strict).unwrap();
+                assert_eq!(tracker.floor(), count_floors(&instructions) as i64, "{}", input);
+                assert_eq!(tracker.basement(), basement, "{}", input);
+                assert_eq!(tracker.position(), input.len() as u64);
+            }
+        }
+    }
+
+    #[test]
+    fn test_track_floors_errors() {
+        for input in ["(()x", "))(x", "))x("] {
+            let e = track_floors(Trickle(input.as_bytes(), 2), Syntax::Strict).unwrap_err();
+            assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+            assert_eq!(e.to_string(), format!("Invalid character 'x' at byte {}", input.find('x').unwrap()));
+        }
+        let mut tracker = FloorTracker::new();
+        assert!(tracker.feed(b"()\n").is_err());
+        assert_eq!(tracker.position(), 2);
+    }
+
+    #[test]
+    fn test_parse_lenient() {
+        let input = "# Instructions\n(()\t)) # down\n ()(\r\n#\n";
+        assert_eq!(parse_instructions(input, Syntax::Lenient), Ok(parsed("(()))()(")));
+        assert_eq!(parse_instructions(input, Syntax::Strict), Err(InstructionError { offset: 0, found: '#' }));
+        assert_eq!(parse_instructions("(()\n", Syntax::Strict), Err(InstructionError { offset: 3, found: '\n' }));
+        assert_eq!(parse_instructions("(é)x", Syntax::Lenient), Err(InstructionError { offset: 1, found: 'é' }));
+        assert_eq!(parse_instructions("(é)x", Syntax::Lenient).unwrap_err().to_string(), "Invalid character '\\u{e9}' at byte 1");
+        assert_eq!(parse_instructions("# é\n)x", Syntax::Lenient), Err(InstructionError { offset: 6, found: 'x' }));
+
+        let mut params = Params::defaults(Day01::PARAMS);
+        assert!(Day01::parse_with("()(\n", &params).is_err());
+        params.set("lenient", 1).unwrap();
+        assert_eq!(Day01::parse_with("()(\n", &params), Ok(parsed("()(")));
+        let e = Day01::parse_with("()\n(x", &params).unwrap_err();
+        assert_eq!((e.line, e.column, e.message.as_str()), (2, 2, "Invalid character 'x'"));
+    }
+
+    #[test]
+    fn test_track_floors_lenient() {
+        let input = "# up (((\n(()\t)) # down )))\n ()(\r\n";
+        let instructions = parse_instructions(input, Syntax::Lenient).unwrap();
+        for size in [1, 2, 5, 100] {
+            let tracker = track_floors(Trickle(input.as_bytes(), size), Syntax::Lenient).unwrap();
+            assert_eq!(tracker.floor(), count_floors(&instructions) as i64);
+            assert_eq!(tracker.basement(), trigger_basement(&instructions).map(|p| p as u64));
+            assert_eq!(tracker.position(), instructions.len() as u64);
+        }
+        let e = track_floors(Trickle("))\n# é\n(é".as_bytes(), 3), Syntax::Lenient).unwrap_err();
+        assert_eq!(e.to_string(), "Invalid character '\\u{fffd}' at byte 9");
+        assert!(track_floors("()\n".as_bytes(), Syntax::Strict).is_err());
+    }
+
+    #[test]
+    fn test_parse_and_track_agree() {
+        let inputs = [
+            "()\u{a0})", "()\x0b)", "(\u{85})", "(\x0c)\t\r\n", "( ) # é \u{a0}\n)", "#\n#", "(\u{2028})", ")x", "",
+        ];
+        for syntax in [Syntax::Strict, Syntax::Lenient] {
+            for input in inputs {
+                let tracked = track_floors(Trickle(input.as_bytes(), 1), syntax)
+                    .map(|t| t.position())
+                    .map_err(|e| e.into_inner().unwrap().downcast::<InstructionError>().unwrap().offset);
+                let parsed = parse_instructions(input, syntax).map(|i| i.len() as u64).map_err(|e| e.offset);
+                assert_eq!(tracked, parsed, "{:?} {:?}", syntax, input);
+            }
+        }
+        assert!(parse_instructions("()\u{a0})", Syntax::Lenient).is_err());
+        assert_eq!(parse_instructions("()\x0b)", Syntax::Lenient), Ok(parsed("())")));
+    }
+}
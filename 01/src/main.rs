@@ -1,65 +1,23 @@
-fn main() {
-    let input_filename = String::from("input.txt");
-    let input = std::fs::read_to_string(input_filename).expect("Error reading input file");
+use aoc_common::{input_and_flags_from_args, Answer, InputSource, Solution};
+use not_quite_lisp::{parse_input_with, Day01, Syntax};
+use std::process::ExitCode;
 
-    println!("Answer #1 is {}", count_floors(&input));
-    println!("Answer #1 is {}", trigger_basement(&input));
-}
-
-fn process_char(c: char, count: &mut i32) {
-    if  c == '(' {
-        *count += 1;
-    } else if c == ')' {
-        *count -= 1;
-    } else {
-        panic!("Encountered invalid character '{c}'");
-    }
-}
-
-fn count_floors(input_str: &str) -> i32 {
-    let mut count = 0;
-    for c in input_str.chars() {
-        process_char(c, &mut count);
-    }
-    count
-}
-
-fn trigger_basement(input_str: &str) -> i32 {
-    let mut count = 0;
-    for (i, c) in input_str.chars().enumerate() {
-        process_char(c, &mut count);
-        if count == -1 {
-            return (i as i32) + 1;
-        }
-    }
-    -1
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::{count_floors, trigger_basement};
-    #[test]
-    fn test_count_floor() {
-        assert_eq!(count_floors(""), 0);
-
-        assert_eq!(count_floors("(())"), 0);
-        assert_eq!(count_floors("()()"), 0);
-        
-        assert_eq!(count_floors("((("), 3);
-        assert_eq!(count_floors("(()(()("), 3);
-        assert_eq!(count_floors("))((((("), 3);
-        
-        assert_eq!(count_floors("())"), -1);
-        assert_eq!(count_floors("))("), -1);
-        
-        assert_eq!(count_floors(")))"), -3);
-        assert_eq!(count_floors(")())())"), -3);
-    }
-
-    #[test]
-    fn test_trigger_basement() {
-        assert_eq!(trigger_basement(""), -1);
-        assert_eq!(trigger_basement(")"), 1);
-        assert_eq!(trigger_basement("()())"), 5);
+fn main() -> ExitCode {
+    // `--lenient` skips whitespace and comments, like `aoc2015 run 1 --set 01.lenient=1`
+    let (source, input, flags) = match input_and_flags_from_args(InputSource::File("input.txt".into()), &["--lenient"]) {
+        Ok(i) => i,
+        Err(code) => return code,
+    };
+    let syntax = if flags.is_empty() { Syntax::Strict } else { Syntax::Lenient };
+    match parse_input_with(&input, syntax) {
+        Ok(parsed) => {
+            println!("Answer #1 is {}", Day01::part1(&parsed));
+            println!("Answer #2 is {}", Day01::part2(&parsed).to_answer().unwrap_or_default());
+            ExitCode::SUCCESS
+        },
+        Err(e) => {
+            eprintln!("{}", source.attach_to(e));
+            ExitCode::FAILURE
+        },
     }
 }
@@ -0,0 +1,150 @@
+use aoc_common::{parse_lines, parse_value, Generator, ParseError, Rng, Solution};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const NAME: &'static str = "i_was_told_there_would_be_no_math";
+
+    type Input = Vec<Box>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        input.iter()
+            .map(|b| b.wrapping_paper_area())
+            .sum()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        input.iter()
+            .map(|b| b.ribbon_length())
+            .sum()
+    }
+}
+
+impl Generator for Day02 {
+    const DEFAULT_SIZE: usize = 1000;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| format!("{}x{}x{}\n", rng.range(1..=30), rng.range(1..=30), rng.range(1..=30)))
+            .collect()
+    }
+}
+
+#[derive(PartialEq)]
+#[derive(Debug)]
+pub struct Box {
+    pub length: i64,
+    pub width: i64,
+    pub height: i64
+}
+
+impl Box {
+    pub fn surface_area(&self) -> i64 {
+        2*self.length*self.width + 2*self.width*self.height + 2*self.height*self.length
+    }
+
+    pub fn smallest_side_area(&self) -> i64 {
+        (self.length*self.width).min(self.width*self.height).min(self.height*self.length)
+    }
+
+    pub fn wrapping_paper_area(&self) -> i64 {
+        self.surface_area() + self.smallest_side_area()
+    }
+
+    pub fn volume(&self) -> i64 {
+        self.length * self.width * self.height
+    }
+
+    pub fn smallest_circumference(&self) -> i64 {
+        let mut v = [self.length, self.width, self.height];
+        v.sort();
+        2* (v[0] + v[1])
+    }
+
+    pub fn ribbon_length(&self) -> i64 {
+        self.smallest_circumference() + self.volume()
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Box>, ParseError> {
+    parse_lines(input, parse_line)
+}
+
+pub fn parse_line(line: &str) -> Result<Box, ParseError> {
+    let triple: Vec<_> = line.split('x').collect();
+    if triple.len() != 3 {
+        return Err(ParseError::new(line, line, "Expected dimensions of the form LxWxH"));
+    }
+    Ok(Box {
+        length: parse_value(line, triple[0])?,
+        width: parse_value(line, triple[1])?,
+        height: parse_value(line, triple[2])?
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{parse_input, parse_line};
+    use crate::{Box, Day02};
+    use aoc_common::{Generator, Rng, Solution};
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(parse_line("1x2x3"), Ok(Box{ length: 1, width: 2, height: 3 }));
+        assert_eq!(parse_line("55x222x333"), Ok(Box{ length: 55, width: 222, height: 333 }));
+        assert_eq!(parse_line("1x2").unwrap_err().column, 1);
+        assert_eq!(parse_line("1x2xa3").unwrap_err().column, 5);
+        assert_eq!(parse_input("1x2x3\n1x2x3x4").unwrap_err().line, 2);
+    }
+
+    #[test]
+    fn test_surface_area() {
+        assert_eq!(Box{ length: 2, width: 3, height: 4 }.surface_area(), 52);
+        assert_eq!(Box{ length: 1, width: 1, height: 10 }.surface_area(), 42);
+    }
+
+    #[test]
+    fn test_smallest_side_area() {
+        assert_eq!(Box{ length: 2, width: 3, height: 4 }.smallest_side_area(), 6);
+        assert_eq!(Box{ length: 1, width: 1, height: 10 }.smallest_side_area(), 1);
+    }
+
+    #[test]
+    fn test_wrapping_paper_area() {
+        assert_eq!(Box{ length: 2, width: 3, height: 4 }.wrapping_paper_area(), 58);
+        assert_eq!(Box{ length: 1, width: 1, height: 10 }.wrapping_paper_area(), 43);
+    }
+    
+    #[test]
+    fn test_volume() {
+        assert_eq!(Box{ length: 2, width: 3, height: 4 }.volume(), 24);
+        assert_eq!(Box{ length: 1, width: 1, height: 10 }.volume(), 10);
+    }
+    
+    #[test]
+    fn test_smallest_circumference() {
+        assert_eq!(Box{ length: 2, width: 3, height: 4 }.smallest_circumference(), 10);
+        assert_eq!(Box{ length: 1, width: 1, height: 10 }.smallest_circumference(), 4);
+    }
+
+    #[test]
+    fn test_ribbon_length() {
+        assert_eq!(Box{ length: 2, width: 3, height: 4 }.ribbon_length(), 34);
+        assert_eq!(Box{ length: 1, width: 1, height: 10 }.ribbon_length(), 14);
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = Day02::generate(&mut Rng::new(seed), 50);
+            assert!(Day02::parse(&input).is_ok(), "{}", input);
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use aoc_common::{Generator, ParseError, Rng, Solution};
+use aoc_common::visual::{self, Frame};
+use aoc_grid::{Direction, Grid, Point};
+use std::collections::HashMap;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const NAME: &'static str = "perfectly_spherical_houses_in_a_vacuum";
+
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        walk_the_map(input).len()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        walk_with_robo_santa(input).len()
+    }
+}
+
+impl Generator for Day03 {
+    const DEFAULT_SIZE: usize = 8192;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        rng.string_from("<>^v", size)
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<String, ParseError> {
+    let input = input.trim_end();
+    match input.char_indices().find(|&(_, c)| Direction::from_char(c).is_none()) {
+        Some((i, c)) => Err(ParseError::at_offset(input, i, format!("Invalid direction '{}'", c.escape_default()))),
+        None => Ok(input.into()),
+    }
+}
+
+pub fn walk_the_map(input: &str) -> HashMap<Point, i32> {
+    let current = Point::default();
+    let counts = HashMap::<Point, i32>::from([(current, 0)]);
+    walk_the_map_impl(input, current, counts)
+}
+
+fn walk_the_map_impl(input: &str, mut current: Point, mut counts: HashMap<Point, i32>) -> HashMap<Point, i32> {
+    for (i, c) in input.chars().enumerate() {
+        current = current.step(Direction::from_char(c).expect("Directions are checked by parse_input"));
+        *counts.entry(current).or_insert(0) += 1;
+        if i % 100 == 99 {
+            show_houses(&counts, i + 1);
+        }
+    }
+    show_houses(&counts, input.len());
+    counts
+}
+
+// Visited houses within the area walked so far
+fn show_houses(counts: &HashMap<Point, i32>, moves: usize) {
+    visual::show(|| {
+        let (min_x, max_x) = counts.keys().map(|p| p.x).fold((0, 0), |(lo, hi), x| (lo.min(x), hi.max(x)));
+        let (min_y, max_y) = counts.keys().map(|p| p.y).fold((0, 0), |(lo, hi), y| (lo.min(y), hi.max(y)));
+        let (width, height) = ((max_x - min_x + 1) as usize, (max_y - min_y + 1) as usize);
+        let houses = Grid::from_fn(width, height, |x, y| counts.contains_key(&Point::new(min_x + x as i64, min_y + y as i64)));
+        Frame {
+            title: format!("{} moves, {} houses visited", moves, counts.len()),
+            lines: houses.shaded(visual::MAX_WIDTH, visual::MAX_HEIGHT, |&visited| if visited { 1.0 } else { 0.0 }),
+        }
+    });
+}
+
+pub fn walk_with_robo_santa(input: &str) -> HashMap<Point, i32> {
+    let m = walk_the_map(&input.chars().step_by(2).collect::<String>());
+    walk_the_map_impl(&input.chars().skip(1).step_by(2).collect::<String>(), Point::default(), m)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_input, walk_the_map, walk_with_robo_santa, Day03};
+    use aoc_grid::Point;
+    use aoc_common::{Generator, Rng, Solution};
+    #[test]
+    fn test_walk_positions() {
+        let m = walk_the_map("^>v<");
+        for p in [Point::new(0, 0), Point::new(0, -1), Point::new(1, -1), Point::new(1, 0)] {
+            assert!(m.contains_key(&p), "{:?}", p);
+        }
+        assert_eq!(m[&Point::new(0, 0)], 1);
+    }
+
+    #[test]
+    fn test_walk_the_map() {
+        assert_eq!(walk_the_map(">").len(), 2);
+        assert_eq!(walk_the_map("^>v<").len(), 4);
+        assert_eq!(walk_the_map("^v^v^v^v^v").len(), 2);
+    }
+
+    #[test]
+    fn test_walk_with_robo_santa() {
+        assert_eq!(walk_with_robo_santa("^v").len(), 3);
+        assert_eq!(walk_with_robo_santa("^>v<").len(), 3);
+        assert_eq!(walk_with_robo_santa("^v^v^v^v^v").len(), 11);
+    }
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(parse_input("^>v<\n"), Ok("^>v<".into()));
+        assert_eq!(parse_input("^>x<").unwrap_err().column, 3);
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = Day03::generate(&mut Rng::new(seed), 200);
+            assert!(Day03::parse(&input).is_ok(), "{}", input);
+        }
+    }
+}
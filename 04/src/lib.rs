@@ -0,0 +1,116 @@
+use aoc_common::{trace, Budget, LOWERCASE, Generator, Outcome, ParseError, Rng, Solution};
+
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const NAME: &'static str = "the_ideal_stocking_stuffer";
+
+    type Input = String;
+    type Answer1 = Outcome<i32>;
+    type Answer2 = Outcome<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.trim().into())
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        try_hashes_within(input, check_hash, &Budget::current())
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        try_hashes_within(input, check_hash2, &Budget::current())
+    }
+}
+
+impl Generator for Day04 {
+    const DEFAULT_SIZE: usize = 8;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        rng.string_from(LOWERCASE, size)
+    }
+}
+
+pub fn try_hashes(input: &str) ->i32 {
+    try_hashes_within(input, check_hash, &Budget::new()).done().expect("Unlimited budget")
+}
+
+pub fn try_hashes2(input: &str) ->i32 {
+    try_hashes_within(input, check_hash2, &Budget::new()).done().expect("Unlimited budget")
+}
+
+// Without a matching hash this only stops when the budget runs out, a step is a hash
+pub fn try_hashes_within(input: &str, check_func: fn(data: &str) -> bool, budget: &Budget) -> Outcome<i32> {
+    let mut count = 0;
+    loop {
+        if check_func(&format!("{}{}", input, count)) {
+            break;
+        }
+        count += 1;
+        if count % 0x10000 == 0 {
+            trace!("progress", hashes = count);
+        }
+        if count % 0x400 == 0 && budget.is_exhausted() {
+            return Outcome::Stopped { partial: None, steps: count as u64 };
+        }
+    }
+    Outcome::Done(count)
+}
+
+pub fn check_hash(data: &str) -> bool {
+    let digest = md5::compute(data);
+    digest.iter().take(2).all(|x| *x == 0) && *digest.get(2).unwrap() < 16
+}
+
+pub fn check_hash2(data: &str) -> bool {
+    let digest = md5::compute(data);
+    digest.iter().take(3).all(|x| *x == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::check_hash;
+    use crate::try_hashes;
+    use crate::try_hashes_within;
+    use crate::Day04;
+    use aoc_common::{Budget, Generator, Outcome, Rng, Solution};
+    use std::time::Duration;
+
+    #[test]
+    fn test_md5() {
+        assert_eq!(format!("{:?}", md5::compute("abcdef609043")), "000001dbbfa3a5c83a2d506429c7b00e"); 
+        assert_eq!(format!("{:?}", md5::compute("pqrstuv1048970")), "000006136ef2ff3b291c85725f17325c"); 
+    }
+    #[test]
+    fn test_check_hash() {
+        assert!(!check_hash("abcdef"));
+        assert!(check_hash("abcdef609043"));
+        assert!(!check_hash("pqrstuv"));
+        assert!(check_hash("pqrstuv1048970"));
+    }
+
+    #[test]
+    fn test_try_hashes() {
+        assert_eq!(try_hashes("abcdef"), 609043);
+        assert_eq!(try_hashes("pqrstuv"), 1048970);
+    }
+
+    #[test]
+    fn test_try_hashes_budget() {
+        let cancelled = Budget::new();
+        cancelled.cancel();
+        assert_eq!(try_hashes_within("abcdef", |_| false, &cancelled), Outcome::Stopped { partial: None, steps: 0x400 });
+        let budget = Budget::new().with_timeout(Duration::from_millis(20));
+        assert!(matches!(try_hashes_within("abcdef", |_| false, &budget), Outcome::Stopped { partial: None, .. }));
+        assert_eq!(try_hashes_within("abcdef", |s| s.ends_with('7'), &cancelled), Outcome::Done(7));
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = Day04::generate(&mut Rng::new(seed), 8);
+            assert!(Day04::parse(&input).is_ok(), "{}", input);
+        }
+    }
+}
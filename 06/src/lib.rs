@@ -0,0 +1,232 @@
+use aoc_common::visual::{self, Frame};
+use aoc_common::{line_struct, parse_lines, FromLine, Generator, ParseError, Rng, Solution};
+use aoc_grid::{Grid, Point};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const NAME: &'static str = "probably_a_fire_hazard";
+
+    type Input = Vec<Instruction>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        let mut f = new_field();
+        for (n, i) in input.iter().enumerate() {
+            process_instruction(&mut f, i);
+            show_field(&f, 1, n + 1, input.len());
+        }
+        count(&f)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        let mut f = new_field();
+        for (n, i) in input.iter().enumerate() {
+            process_instruction2(&mut f, i);
+            show_field(&f, 2, n + 1, input.len());
+        }
+        count(&f)
+    }
+}
+
+impl Generator for Day06 {
+    const DEFAULT_SIZE: usize = 300;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut r = String::new();
+        for _ in 0..size {
+            let command = rng.choose(&["turn on", "turn off", "toggle"]);
+            let (x1, x2) = (rng.range(0..=999), rng.range(0..=999));
+            let (y1, y2) = (rng.range(0..=999), rng.range(0..=999));
+            r += &format!("{} {},{} through {},{}\n", command, x1.min(x2), y1.min(y2), x1.max(x2), y1.max(y2));
+        }
+        r
+    }
+}
+
+pub type Field = Grid<u32>;
+
+pub fn new_field() -> Field {
+    Grid::new(1000, 1000, 0)
+}
+
+pub fn count(f: &Field) -> i32 {
+    f.iter().sum::<u32>() as i32
+}
+
+// Downsampled, the brightest light in the field is shown fully on
+fn show_field(f: &Field, part: u8, done: usize, total: usize) {
+    visual::show(|| {
+        let brightest = f.iter().copied().max().unwrap_or(0).max(1) as f64;
+        Frame {
+            title: format!("Part {}, instruction {}/{}, total brightness {}", part, done, total, count(f)),
+            lines: f.shaded(visual::MAX_WIDTH, visual::MAX_HEIGHT, |&v| v as f64 / brightest),
+        }
+    });
+}
+
+line_struct! {
+    struct Line = "{} {},{} through {},{}" {
+        command: Command = parse_command_field,
+        x1: i64 where in_range => "Coordinate outside of 0..1000",
+        y1: i64 where in_range => "Coordinate outside of 0..1000",
+        x2: i64 where in_range => "Coordinate outside of 0..1000",
+        y2: i64 where in_range => "Coordinate outside of 0..1000",
+    }
+}
+
+fn in_range(v: &i64) -> bool {
+    (0..1000).contains(v)
+}
+
+fn parse_command_field(line: &str, c: &str) -> Result<Command, ParseError> {
+    parse_command(c).ok_or_else(|| ParseError::new(line, c, "Invalid command"))
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse_lines(input, |l| {
+        let Line { command, x1, y1, x2, y2 } = Line::from_line(l)?;
+        Ok(Instruction { command, from: Point::new(x1, y1), to: Point::new(x2, y2) })
+    })
+}
+
+pub fn parse_command(c: &str) -> Option<Command> {
+    match c {
+        "turn on" => Some(Command::TurnOn),
+        "turn off" => Some(Command::TurnOff),
+        "toggle" => Some(Command::Toggle),
+        _ => None,
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    TurnOn,
+    TurnOff,
+    Toggle,
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    pub command: Command,
+    pub from: Point,
+    pub to: Point,
+}
+
+pub fn process_instruction(f: &mut Field, instruction: &Instruction) {
+    let op = match instruction.command {
+        Command::TurnOn => |_c: u32| -> u32 { 1 },
+        Command::TurnOff => |_c: u32| -> u32 { 0 },
+        Command::Toggle => |c: u32| -> u32 { 1 - c },
+    };
+    for iy in instruction.from.y..(instruction.to.y + 1) {
+        let y = iy as usize;
+        for ix in instruction.from.x..(instruction.to.x + 1) {
+            let x = ix as usize;
+            f[(x, y)] = op(f[(x, y)]);
+        }
+    }
+}
+
+pub fn process_instruction2(f: &mut Field, instruction: &Instruction) {
+    let op = match instruction.command {
+        Command::TurnOn => |c: u32| -> u32 { c + 1 },
+        Command::TurnOff => |c: u32| -> u32 {
+            if c == 0 {
+                0
+            } else {
+                c - 1
+            }
+        },
+        Command::Toggle => |c: u32| -> u32 { c + 2 },
+    };
+    for iy in instruction.from.y..(instruction.to.y + 1) {
+        let y = iy as usize;
+        for ix in instruction.from.x..(instruction.to.x + 1) {
+            let x = ix as usize;
+            f[(x, y)] = op(f[(x, y)]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_parse_input() {
+        let test_input = concat!(
+            "turn on 0,0 through 999,999",
+            "\n",
+            "toggle 0,0 through 999,0",
+            "\n",
+            "turn off 499,499 through 500,500"
+        );
+        let instr = parse_input(test_input).unwrap();
+        assert_eq!(instr.len(), 3);
+        assert_eq!(instr[0].command, Command::TurnOn);
+        assert_eq!(instr[0].from, Point { x: 0, y: 0 });
+        assert_eq!(instr[0].to, Point { x: 999, y: 999 });
+
+        assert_eq!(instr[1].command, Command::Toggle);
+        assert_eq!(instr[1].from, Point { x: 0, y: 0 });
+        assert_eq!(instr[1].to, Point { x: 999, y: 0 });
+
+        assert_eq!(instr[2].command, Command::TurnOff);
+        assert_eq!(instr[2].from, Point { x: 499, y: 499 });
+        assert_eq!(instr[2].to, Point { x: 500, y: 500 });
+    }
+
+    #[test]
+    fn test_parse_input_errors() {
+        let e = parse_input("turn on 0,0 through 999,999\ntoggle 0,0 through 1000,0").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 20));
+        let e = parse_input("turn sideways 0,0 through 999,999").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 1));
+    }
+
+    #[test]
+    fn test_process_instruction() {
+        let mut f = new_field();
+        process_instruction(
+            &mut f,
+            &Instruction {
+                command: Command::TurnOn,
+                from: Point { x: 0, y: 0 },
+                to: Point { x: 999, y: 999 },
+            },
+        );
+        for iy in 0..1000 {
+            for ix in 0..1000 {
+                assert_eq!(f[(ix, iy)], 1);
+            }
+        }
+        process_instruction(
+            &mut f,
+            &Instruction {
+                command: Command::Toggle,
+                from: Point { x: 0, y: 0 },
+                to: Point { x: 999, y: 0 },
+            },
+        );
+        for iy in 0..1000 {
+            for ix in 0..1000 {
+                assert_eq!(f[(ix, iy)], if iy == 0 { 0 } else { 1 });
+            }
+        }
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = Day06::generate(&mut Rng::new(seed), 20);
+            assert!(Day06::parse(&input).is_ok(), "{}", input);
+        }
+    }
+}
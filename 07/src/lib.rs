@@ -0,0 +1,447 @@
+use aoc_common::{line_struct, lowercase_name, parse_lines, parse_value, trace, FromLine, Generator, ParseError, Rng, Solution};
+use std::collections::HashMap;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const NAME: &'static str = "some_assembly_required";
+
+    type Input = Vec<Command>;
+    type Answer1 = u16;
+    type Answer2 = u16;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        execute_program(input).value("a")
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        let mut commands = input.clone();
+        override_wires(&mut commands, Self::part1(input));
+        execute_program(&commands).value("a")
+    }
+}
+
+impl Generator for Day07 {
+    const DEFAULT_SIZE: usize = 340;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // Every wire only reads wires defined before it, which keeps the circuit acyclic.
+        // "b" is a plain signal so part 2 can override it, "a" is defined last.
+        let size = size.max(2);
+        let mut names = vec!["b".to_string()];
+        let mut lines = vec![format!("{} -> b", rng.range(0..=65535))];
+        for i in 1..size {
+            let dest = if i == size - 1 { "a".to_string() } else { lowercase_name(i + 1) };
+            let wire = rng.choose(&names).clone();
+            let other = rng.choose(&names).clone();
+            let op = match rng.index(6) {
+                0 => wire,
+                1 => format!("NOT {}", wire),
+                2 => format!("{} AND {}", wire, other),
+                3 => format!("{} OR {}", if rng.chance(0.2) { rng.range(0..=65535).to_string() } else { wire }, other),
+                4 => format!("{} LSHIFT {}", wire, rng.range(1..=15)),
+                _ => format!("{} RSHIFT {}", wire, rng.range(1..=15)),
+            };
+            lines.push(format!("{} -> {}", op, dest));
+            names.push(dest);
+        }
+        rng.shuffle(&mut lines);
+        lines.iter().map(|l| format!("{}\n", l)).collect()
+    }
+}
+
+pub fn override_wires(commands : &mut Vec<Command>, override_value: u16) {
+    for c in commands {
+        if c.destination == "b" {
+            c.op = Operation::Assign(Operand::Number(override_value));
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Operand {
+    Number(u16),
+    Register(String),
+}
+
+impl Operand {
+    pub fn parse(line: &str, str: &str) -> Result<Operand, ParseError> {
+        if str.chars().all(|c| c.is_ascii_digit()) {
+            Ok(Operand::Number(parse_value(line, str)?))
+        } else {
+            Ok(Operand::Register(String::from(str)))
+        }
+    }
+
+    pub fn resolve(&self, w: &Wires) -> u16 {
+        match self {
+            Operand::Number(n) => *n,
+            Operand::Register(r) => w.value(r),
+        }
+    }
+
+    pub fn is_ready(&self, w: &Wires) -> bool {
+        match self {
+            Operand::Number(_) => true,
+            Operand::Register(r) => w.wire_has_value(r),
+        }
+    }
+}
+
+impl std::fmt::Display for Operand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Operand::Number(n) => write!(f, "{}", n),
+            Operand::Register(r) => write!(f, "{}", r),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Operation {
+    Assign(Operand),
+    Not(Operand),
+    And(Operand, Operand),
+    Or(Operand, Operand),
+    Lshift(Operand, Operand),
+    Rshift(Operand, Operand),
+}
+
+impl Operation {
+    pub fn parse(line: &str, s: &str) -> Result<Operation, ParseError> {
+        match s.split(' ').collect::<Vec<_>>()[..] {
+            [src] => Ok(Operation::Assign(Operand::parse(line, src)?)),
+            ["NOT", src] => Ok(Operation::Not(Operand::parse(line, src)?)),
+            [lhs, opcode, rhs] => {
+                let lhs = Operand::parse(line, lhs)?;
+                let rhs = Operand::parse(line, rhs)?;
+                match opcode {
+                    "AND" => Ok(Operation::And(lhs, rhs)),
+                    "OR" => Ok(Operation::Or(lhs, rhs)),
+                    "LSHIFT" => Ok(Operation::Lshift(lhs, rhs)),
+                    "RSHIFT" => Ok(Operation::Rshift(lhs, rhs)),
+                    _ => Err(ParseError::new(line, s, format!("Invalid opcode '{}'", opcode))),
+                }
+            },
+            _ => Err(ParseError::new(line, s, "Expected '<operand>', 'NOT <operand>' or '<operand> <opcode> <operand>'")),
+        }
+    }
+
+    pub fn execute(&self, wires: &Wires) -> u16 {
+        match self {
+            Operation::Assign(src) => {
+                src.resolve(wires)
+            },
+            Operation::Not(op) => {
+                !op.resolve(wires)
+            },
+            Operation::And(lhs, rhs) => {
+                lhs.resolve(wires) & rhs.resolve(wires)
+             },
+            Operation::Or(lhs, rhs) => {
+                lhs.resolve(wires) | rhs.resolve(wires)
+             },
+            // Shifting a wire by 16 or more clears it, amounts from other wires can be that large
+            Operation::Lshift(lhs, rhs) => {
+                lhs.resolve(wires).checked_shl(rhs.resolve(wires).into()).unwrap_or(0)
+            },
+            Operation::Rshift(lhs, rhs) => {
+                lhs.resolve(wires).checked_shr(rhs.resolve(wires).into()).unwrap_or(0)
+             },
+        }
+    }
+
+    pub fn operands(&self) -> Vec<&Operand> {
+        match self {
+            Operation::Assign(op) | Operation::Not(op) => vec![op],
+            Operation::And(lhs, rhs) | Operation::Or(lhs, rhs) | Operation::Lshift(lhs, rhs) | Operation::Rshift(lhs, rhs) => {
+                vec![lhs, rhs]
+            },
+        }
+    }
+
+    pub fn is_ready(&self, wires: &Wires) -> bool {
+        match self {
+            Operation::Assign(src) => {
+                src.is_ready(wires)
+            },
+            Operation::Not(op) => {
+                op.is_ready(wires)
+            },
+            Operation::And(lhs, rhs) => {
+                lhs.is_ready(wires) && rhs.is_ready(wires)
+             },
+            Operation::Or(lhs, rhs) => {
+                lhs.is_ready(wires) && rhs.is_ready(wires)
+             },
+            Operation::Lshift(lhs, rhs) => {
+                lhs.is_ready(wires) && rhs.is_ready(wires)
+            },
+            Operation::Rshift(lhs, rhs) => {
+                lhs.is_ready(wires) && rhs.is_ready(wires)
+             },
+        }
+    }
+}
+
+impl std::fmt::Display for Operation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Operation::Assign(src) => write!(f, "{}", src),
+            Operation::Not(op) => write!(f, "NOT {}", op),
+            Operation::And(lhs, rhs) => write!(f, "{} AND {}", lhs, rhs),
+            Operation::Or(lhs, rhs) => write!(f, "{} OR {}", lhs, rhs),
+            Operation::Lshift(lhs, rhs) => write!(f, "{} LSHIFT {}", lhs, rhs),
+            Operation::Rshift(lhs, rhs) => write!(f, "{} RSHIFT {}", lhs, rhs),
+        }
+    }
+}
+
+line_struct! {
+    #[derive(Debug, PartialEq, Eq, Clone)]
+    pub struct Command = "{} -> {}" {
+        pub op: Operation = Operation::parse,
+        pub destination: String,
+    }
+}
+
+impl std::fmt::Display for Command {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} -> {}", self.op, self.destination)
+    }
+}
+
+impl Command {
+    pub fn execute(&self, wires: &mut Wires) {
+        let result = self.op.execute(wires);
+        wires.assign(&self.destination, result);
+    }
+    
+    pub fn is_ready(&self, wires: &Wires) -> bool {
+        self.op.is_ready(wires)
+    }
+}
+
+pub struct Wires {
+    m: HashMap<String, u16>,
+}
+
+impl Default for Wires {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Wires {
+    pub fn new() -> Wires {
+        Wires {
+            m: HashMap::new(),
+        }
+    }
+
+    pub fn wire_has_value(&self, wire_name: &str) -> bool {
+        self.m.contains_key(wire_name)
+    }
+
+    pub fn assign(&mut self, wire_name: &str, value: u16) {
+        assert!(!self.wire_has_value(wire_name));
+        self.m.insert(String::from(wire_name), value);
+    }
+
+    pub fn value(&self, wire_name: &str) -> u16 {
+        assert!(self.wire_has_value(wire_name));
+        *self.m.get(wire_name).unwrap()
+    }
+}
+
+pub fn execute_program(commands: &[Command]) -> Wires {
+    let mut wires = Wires::new();
+    let mut skip_set = std::collections::HashSet::<usize>::new();
+    while skip_set.len() != commands.len() {
+        let mut did_progress = false;
+        for (i, c) in commands.iter().enumerate() {
+            if !skip_set.contains(&i)
+                && c.is_ready(&wires) {
+                    c.execute(&mut wires);
+                    trace!("execute", line = i + 1, command = c, value = wires.value(&c.destination));
+                    skip_set.insert(i);
+                    did_progress = true;
+                }
+        }
+        assert!(did_progress);
+    }
+    wires
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Command>, ParseError> {
+    let commands = parse_circuit(input)?;
+    if !commands.iter().any(|c| c.destination == "a") {
+        return Err(ParseError::at_offset(input, input.len(), "No instruction assigns wire 'a'"));
+    }
+    Ok(commands)
+}
+
+// A circuit that `execute_program` can evaluate, without needing a wire 'a' like the examples
+pub fn parse_circuit(input: &str) -> Result<Vec<Command>, ParseError> {
+    let commands = parse_lines(input, Command::from_line)?;
+    check_circuit(input, &commands)?;
+    Ok(commands)
+}
+
+// Part of `line` to point at for `word`, the whole line if it is not one of its words
+fn word_in<'a>(line: &'a str, word: &str) -> &'a str {
+    line.split(' ').rfind(|w| *w == word).unwrap_or(line)
+}
+
+// What `execute_program` relies on: every wire is assigned once, only reads wires that are assigned and there
+// are no loops
+fn check_circuit(input: &str, commands: &[Command]) -> Result<(), ParseError> {
+    let lines: Vec<_> = input.lines().collect();
+    let error = |i: usize, word: &str, message: String| ParseError::new(lines[i], word_in(lines[i], word), message).on_line(i + 1);
+
+    let mut sources = HashMap::new();
+    for (i, c) in commands.iter().enumerate() {
+        if let Some(first) = sources.insert(c.destination.as_str(), i) {
+            return Err(error(i, &c.destination, format!("Wire '{}' is already assigned on line {}", c.destination, first + 1)));
+        }
+    }
+    let mut dependencies = vec![Vec::new(); commands.len()];
+    for (i, c) in commands.iter().enumerate() {
+        if let Operation::Lshift(_, Operand::Number(n)) | Operation::Rshift(_, Operand::Number(n)) = &c.op {
+            if *n > 15 {
+                return Err(error(i, &n.to_string(), format!("Shift by {} is more than the 16 bits of a wire", n)));
+            }
+        }
+        for op in c.op.operands() {
+            if let Operand::Register(wire) = op {
+                match sources.get(wire.as_str()) {
+                    Some(&source) => dependencies[i].push(source),
+                    None => return Err(error(i, wire, format!("Wire '{}' is never assigned", wire))),
+                }
+            }
+        }
+    }
+
+    // Takes away commands whose inputs are known until only loops and what depends on them are left
+    let mut waiting: Vec<_> = dependencies.iter().map(|d| d.len()).collect();
+    let mut dependents = vec![Vec::new(); commands.len()];
+    for (i, deps) in dependencies.iter().enumerate() {
+        for &d in deps {
+            dependents[d].push(i);
+        }
+    }
+    let mut ready: Vec<_> = (0..commands.len()).filter(|&i| waiting[i] == 0).collect();
+    while let Some(i) = ready.pop() {
+        for &d in &dependents[i] {
+            waiting[d] -= 1;
+            if waiting[d] == 0 {
+                ready.push(d);
+            }
+        }
+    }
+    let Some(mut i) = (0..commands.len()).find(|&i| waiting[i] > 0) else {
+        return Ok(());
+    };
+    // Following the inputs that are still waiting ends up going around a loop
+    let mut seen = vec![false; commands.len()];
+    while !seen[i] {
+        seen[i] = true;
+        i = *dependencies[i].iter().find(|&&d| waiting[d] > 0).expect("Waiting commands wait for another one");
+    }
+    let wire = &commands[i].destination;
+    Err(error(i, wire, format!("Wire '{}' depends on its own value", wire)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn get_sample_input() -> &'static str {
+        r"123 -> x
+456 -> y
+x AND y -> d
+x OR y -> e
+x LSHIFT 2 -> f
+y RSHIFT 2 -> g
+NOT x -> h
+NOT y -> i"
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let p = parse_circuit(get_sample_input()).unwrap();
+        assert_eq!(p.len(), 8);
+        assert_eq!(p[0], Command{ op: Operation::Assign(Operand::Number(123)), destination: String::from("x") });
+        assert_eq!(p[1], Command{ op: Operation::Assign(Operand::Number(456)), destination: String::from("y") });
+        assert_eq!(p[2], Command{ op: Operation::And(Operand::Register(String::from("x")), Operand::Register(String::from("y"))), destination: String::from("d") });
+        assert_eq!(p[3], Command{ op: Operation::Or(Operand::Register(String::from("x")), Operand::Register(String::from("y"))), destination: String::from("e") });
+        assert_eq!(p[4], Command{ op: Operation::Lshift(Operand::Register(String::from("x")), Operand::Number(2)), destination: String::from("f") });
+        assert_eq!(p[5], Command{ op: Operation::Rshift(Operand::Register(String::from("y")), Operand::Number(2)), destination: String::from("g") });
+        assert_eq!(p[6], Command{ op: Operation::Not(Operand::Register(String::from("x"))), destination: String::from("h") });
+        assert_eq!(p[7], Command{ op: Operation::Not(Operand::Register(String::from("y"))), destination: String::from("i") });
+    }
+
+    #[test]
+    fn test_display() {
+        let input = "123 -> x\nNOT x -> h\nx AND 7 -> d\nd RSHIFT 2 -> g";
+        let text: Vec<_> = parse_circuit(input).unwrap().iter().map(|c| c.to_string()).collect();
+        assert_eq!(text.join("\n"), input);
+    }
+
+    #[test]
+    fn test_parse_input_errors() {
+        let e = parse_input("123 -> x\nx XOR y -> z").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 1, "x XOR y -> z"));
+        let e = parse_input("x AND 70000 -> z").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 7));
+    }
+
+    #[test]
+    fn test_parse_circuit_errors() {
+        let error = |input: &str| parse_input(input).map(|_| ()).map_err(|e| (e.line, e.column, e.message));
+        assert_eq!(error("123 -> b"), Err((1, 9, "No instruction assigns wire 'a'".into())));
+        assert_eq!(error("1 -> b\nb -> a\n2 -> b"), Err((3, 6, "Wire 'b' is already assigned on line 1".into())));
+        assert_eq!(error("1 -> b\nb AND c -> a"), Err((2, 7, "Wire 'c' is never assigned".into())));
+        assert_eq!(error("1 -> b\nb LSHIFT 20 -> a"), Err((2, 10, "Shift by 20 is more than the 16 bits of a wire".into())));
+        assert_eq!(error("a -> a"), Err((1, 6, "Wire 'a' depends on its own value".into())));
+        let e = error("1 -> x\nc -> a\nd OR x -> c\nNOT c -> d").unwrap_err();
+        assert_eq!(e.2, format!("Wire '{}' depends on its own value", ["c", "d"][e.0 - 3]));
+        assert!(parse_input("1 -> b\nb LSHIFT 15 -> a").is_ok());
+    }
+
+    #[test]
+    fn test_large_shifts() {
+        let program = parse_circuit("20 -> s\n65535 -> x\nx LSHIFT s -> a\nx RSHIFT s -> b").unwrap();
+        let wires = execute_program(&program);
+        assert_eq!((wires.value("a"), wires.value("b")), (0, 0));
+    }
+
+    #[test]
+    fn test_execute_program() {
+        let p = parse_circuit(get_sample_input()).unwrap();
+        let wires = execute_program(&p);
+        assert_eq!(wires.m.len(), 8);
+        assert_eq!(wires.value("d"), 72);
+        assert_eq!(wires.value("e"), 507);
+        assert_eq!(wires.value("f"), 492);
+        assert_eq!(wires.value("g"), 114);
+        assert_eq!(wires.value("h"), 65412);
+        assert_eq!(wires.value("i"), 65079);
+        assert_eq!(wires.value("x"), 123);
+        assert_eq!(wires.value("y"), 456);
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = Day07::generate(&mut Rng::new(seed), 100);
+            let program = Day07::parse(&input).unwrap();
+            Day07::part2(&program);
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use aoc_common::{parse_lines, LOWERCASE, Generator, ParseError, Rng, Solution};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const NAME: &'static str = "matchsticks";
+
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        answer1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        answer2(input)
+    }
+}
+
+impl Generator for Day08 {
+    const DEFAULT_SIZE: usize = 300;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut r = String::new();
+        for _ in 0..size {
+            r.push('"');
+            for _ in 0..rng.range(0..=30) {
+                match rng.index(10) {
+                    0 => r += "\\\\",
+                    1 => r += "\\\"",
+                    2 => r += &format!("\\x{:02x}", rng.range(0..=255)),
+                    _ => r += &rng.string_from(LOWERCASE, 1),
+                }
+            }
+            r += "\"\n";
+        }
+        r
+    }
+}
+
+pub fn size_in_code(s: &str) -> usize {
+    s.len()
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseState {
+    Normal,
+    InSlash,
+    AwaitHex1,
+    AwaitHex2,
+}
+
+pub fn resolve_escapes(s: &str) -> Result<(String, usize), ParseError> {
+    let mut ret = String::new();
+    let mut state = ParseState::Normal;
+    let mut hex_buffer = 0;
+    let mut count = 0;
+    if s.len() < 2 || !s.starts_with('\"') || !s.ends_with('\"') {
+        return Err(ParseError::new(s, s, "Expected string enclosed in quotes"));
+    }
+    let inner = &s[1..s.len() - 1];
+    for (i, c) in inner.char_indices() {
+        let at = &inner[i..];
+        match state {
+            ParseState::Normal => {
+                if c == '\\' {
+                    state = ParseState::InSlash;
+                } else {
+                    ret.push(c);
+                    count += 1;
+                }
+            },
+            ParseState::InSlash => {
+                match c {
+                    '\\' => { ret.push('\\'); count += 1; state = ParseState::Normal; },
+                    '\"' => { ret.push('\"'); count += 1; state = ParseState::Normal; },
+                    'x' => { hex_buffer = 0; state = ParseState::AwaitHex1; },
+                    c => { return Err(ParseError::new(s, at, format!("Invalid escape {}", c))); },
+                }
+            },
+            ParseState::AwaitHex1 => {
+                hex_buffer += c.to_digit(16).ok_or_else(|| ParseError::new(s, at, format!("Invalid hex {}", c)))? * 16;
+                state = ParseState::AwaitHex2;
+            },
+            ParseState::AwaitHex2 => {
+                hex_buffer += c.to_digit(16).ok_or_else(|| ParseError::new(s, at, format!("Invalid hex {}", c)))?;
+                ret.push(char::from_u32(hex_buffer).ok_or_else(|| ParseError::new(s, at, format!("Hex escape sequence resolves to invalid char: {}", hex_buffer)))?);
+                count += 1;
+                state = ParseState::Normal;
+            },
+        }
+    }
+    if state != ParseState::Normal {
+        return Err(ParseError::new(s, &s[s.len() - 1..], "Unterminated escape sequence"));
+    }
+    Ok((ret, count))
+}
+
+pub fn parse_input(input: &str) -> Result<String, ParseError> {
+    parse_lines(input, resolve_escapes)?;
+    Ok(input.into())
+}
+
+pub fn size_of_string(s: &str) -> usize {
+    let (_, size) = resolve_escapes(s).expect("Strings are validated by parse_input");
+    size
+}
+
+pub fn size_of_escaped_string(s: &str) -> usize {
+    let mut count = 0;
+    for c in s.chars() {
+        match c {
+            '\\' => { count += 2; },
+            '\"' => { count += 2; },
+            _ => { count += 1; },
+        }
+    }
+    count + 2
+}
+
+pub fn answer1(input: &str) -> usize {
+    let mut acc_string_sizes = 0;
+    let mut acc_code_sizes = 0;
+    for l in input.lines() {
+        acc_string_sizes += size_of_string(l);
+        acc_code_sizes += size_in_code(l);
+    }
+    acc_code_sizes - acc_string_sizes
+}
+
+pub fn answer2(input: &str) -> usize {
+    let mut acc_escaped_size = 0;
+    let mut acc_code_sizes = 0;
+    for l in input.lines() {
+        acc_escaped_size += size_of_escaped_string(l);
+        acc_code_sizes += size_in_code(l);
+    }
+    acc_escaped_size - acc_code_sizes
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn test_string() -> &'static str {
+        r#"""
+"abc"
+"aaa\"aaa"
+"\x27""#
+    }
+
+    #[test]
+    fn test() {
+        let quotes = r#""""#;
+        let abc = r#""abc""#;
+        let slash_quote = r#""aaa\"aaa""#;
+        let slash_x = r#""\x27""#;
+        let slash_slash = r#""a\\b""#;
+        assert_eq!(size_in_code(quotes), 2);
+        assert_eq!(size_in_code(abc), 5);
+        assert_eq!(size_in_code(slash_quote), 10);
+        assert_eq!(size_in_code(slash_x), 6);
+        assert_eq!(size_in_code(slash_slash), 6);
+
+        assert_eq!(size_of_string(quotes), 0);
+        assert_eq!(size_of_string(abc), 3);
+        assert_eq!(size_of_string(slash_quote), 7);
+        assert_eq!(size_of_string(slash_x), 1);
+        assert_eq!(size_of_string(slash_slash), 3);
+
+        assert_eq!(size_of_escaped_string(quotes), 6);
+        assert_eq!(size_of_escaped_string(abc), 9);
+        assert_eq!(size_of_escaped_string(slash_quote), 16);
+        assert_eq!(size_of_escaped_string(slash_x), 11);
+        assert_eq!(size_of_escaped_string(slash_slash), 12);
+    }
+
+    #[test]
+    fn test_resolve_escapes() {
+        assert_eq!(resolve_escapes(r#""a\\b\"c\x41""#), Ok((r#"a\b"cA"#.to_string(), 6)));
+        assert_eq!(resolve_escapes(r#""abc"#).unwrap_err().column, 1);
+        assert_eq!(resolve_escapes(r#""a\qb""#).unwrap_err().column, 4);
+        assert_eq!(resolve_escapes(r#""a\x4g""#).unwrap_err().column, 6);
+        assert_eq!(resolve_escapes(r#""a\""#).unwrap_err().column, 4);
+        assert_eq!(parse_input(test_string()), Ok(test_string().into()));
+        assert_eq!(parse_input("\"a\"\n\"b").unwrap_err().line, 2);
+    }
+
+    #[test]
+    fn test_answer1() {
+        assert_eq!(answer1(test_string()), 12);
+    }
+
+    #[test]
+    fn test_answer2() {
+        assert_eq!(answer2(test_string()), 19);        
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = Day08::generate(&mut Rng::new(seed), 50);
+            assert!(Day08::parse(&input).is_ok(), "{}", input);
+        }
+    }
+}
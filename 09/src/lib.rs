@@ -0,0 +1,248 @@
+use aoc_common::{line_struct, parse_lines, Example, FromLine, Generator, ParseError, Rng, Solution};
+use aoc_tour::{best_tour, Objective, Shape, Tour};
+
+pub struct Day09;
+
+// From the puzzle description
+const SAMPLE: &str = r"London to Dublin = 464
+London to Belfast = 518
+Dublin to Belfast = 141";
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const NAME: &'static str = "all_in_a_single_night";
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(SAMPLE).part1("605").part2("982"),
+    ];
+
+    type Input = Graph;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Graph::new(&parse_input(input)?))
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        input.all_paths().0
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        input.all_paths().1
+    }
+}
+
+impl Generator for Day09 {
+    const DEFAULT_SIZE: usize = 8;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut r = String::new();
+        for from in 0..size {
+            for to in from + 1..size {
+                r += &format!("City{} to City{} = {}\n", from, to, rng.range(1..=200));
+            }
+        }
+        r
+    }
+}
+
+line_struct! {
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct Connection = "{} to {} = {}" {
+        pub from: String,
+        pub to: String,
+        pub distance: i64,
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Connection>, ParseError> {
+    parse_lines(input, Connection::from_line)
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Edge {
+    pub from: usize,
+    pub to: usize,
+    pub distance: i64,
+}
+
+pub struct Graph {
+    cities: Vec<String>,
+    edges: Vec<Vec<Edge>>,
+}
+
+impl Graph {
+    pub fn new(connections: &Vec<Connection>) -> Graph {
+        let mut g = Graph {
+            cities: Vec::new(),
+            edges: Vec::new(),
+        };
+        g.add_connections(connections);
+        g
+    }
+
+    pub fn get_city_index(&mut self, city: &str) -> usize {
+        match self.cities.iter().position(|c| c == city) {
+            Some(idx) => idx,
+            None => {
+                self.cities.push(city.to_string());
+                self.edges.push(Vec::new());
+                self.cities.len() - 1
+            }
+        }
+    }
+
+    pub fn add_connection(&mut self, connection: &Connection) {
+        let from = self.get_city_index(&connection.from);
+        let to = self.get_city_index(&connection.to);
+        let distance = connection.distance;
+        self.edges[from].push(Edge { from, to, distance });
+        self.edges[to].push(Edge {
+            from: to,
+            to: from,
+            distance,
+        });
+    }
+
+    pub fn add_connections(&mut self, connections: &Vec<Connection>) {
+        for c in connections {
+            self.add_connection(c);
+        }
+    }
+
+    pub fn find_distance(&self, from: usize, to: usize) -> Option<i64> {
+        self.edges[from].iter().find(|e| e.to == to).map(|e| e.distance)
+    }
+
+    pub fn distance(&self, from: usize, to: usize) -> i64 {
+        self.find_distance(from, to).unwrap_or_else(|| panic!("No edge from {} to {}", from, to))
+    }
+
+    // Visits every city once, only along known connections
+    pub fn best_route(&self, objective: Objective) -> Option<Tour> {
+        best_tour(self.cities.len(), Shape::Path, objective, |from, to| self.find_distance(from, to))
+    }
+
+    pub fn route_names(&self, tour: &Tour) -> Vec<&str> {
+        tour.order.iter().map(|&i| self.cities[i].as_str()).collect()
+    }
+
+    pub fn all_paths(&self) -> (i64, i64) {
+        let cost = |objective| self.best_route(objective).map_or(0, |t| t.cost);
+        (cost(Objective::Minimize), cost(Objective::Maximize))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn sample_graph() -> Graph {
+        Graph::new(&parse_input(SAMPLE).unwrap())
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let connections = parse_input(SAMPLE).unwrap();
+        assert_eq!(connections.len(), 3);
+        assert_eq!(connections[0].from, "London");
+        assert_eq!(connections[0].to, "Dublin");
+        assert_eq!(connections[0].distance, 464);
+
+        assert_eq!(connections[1].from, "London");
+        assert_eq!(connections[1].to, "Belfast");
+        assert_eq!(connections[1].distance, 518);
+
+        assert_eq!(connections[2].from, "Dublin");
+        assert_eq!(connections[2].to, "Belfast");
+        assert_eq!(connections[2].distance, 141);
+    }
+
+    #[test]
+    fn test_parse_input_errors() {
+        let e = parse_input("London to Dublin = 464\nLondon - Belfast = 518").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+    }
+
+    #[test]
+    fn build_graph() {
+        let g = sample_graph();
+        assert_eq!(g.cities, vec!["London", "Dublin", "Belfast"]);
+        assert_eq!(
+            g.edges[0],
+            vec![
+                Edge {
+                    from: 0,
+                    to: 1,
+                    distance: 464
+                },
+                Edge {
+                    from: 0,
+                    to: 2,
+                    distance: 518
+                },
+            ]
+        );
+        assert_eq!(
+            g.edges[1],
+            vec![
+                Edge {
+                    from: 1,
+                    to: 0,
+                    distance: 464
+                },
+                Edge {
+                    from: 1,
+                    to: 2,
+                    distance: 141
+                },
+            ]
+        );
+        assert_eq!(
+            g.edges[2],
+            vec![
+                Edge {
+                    from: 2,
+                    to: 0,
+                    distance: 518
+                },
+                Edge {
+                    from: 2,
+                    to: 1,
+                    distance: 141
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn minimum_distance() {
+        let g = sample_graph();
+        let (shortest, longest) = g.all_paths();
+        assert_eq!(shortest, 605);
+        assert_eq!(longest, 982);
+    }
+
+    #[test]
+    fn test_best_route() {
+        let g = sample_graph();
+        let t = g.best_route(Objective::Minimize).unwrap();
+        assert_eq!(g.route_names(&t), vec!["London", "Dublin", "Belfast"]);
+        let t = g.best_route(Objective::Maximize).unwrap();
+        assert_eq!(g.route_names(&t), vec!["Dublin", "London", "Belfast"]);
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc_common::check_examples::<Day09>();
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = Day09::generate(&mut Rng::new(seed), 5);
+            assert!(Day09::parse(&input).is_ok(), "{}", input);
+        }
+    }
+}
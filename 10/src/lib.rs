@@ -0,0 +1,133 @@
+use aoc_common::{Budget, Configured, Generator, Outcome, Param, Params, ParseError, Rng, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const NAME: &'static str = "elves_look_elves_say";
+
+    const PARAMS: &'static [Param] = &[
+        Param::new("iterations_part1", 40, "Rounds of look-and-say for part 1").range(0, u32::MAX as i64),
+        Param::new("iterations_part2", 50, "Rounds of look-and-say for part 2").range(0, u32::MAX as i64),
+    ];
+
+    type Input = Configured<String>;
+    type Answer1 = Outcome<usize>;
+    type Answer2 = Outcome<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, ParseError> {
+        Ok(Configured { value: parse_input(input)?, params: params.clone() })
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        look_and_say_within(&input.value, input.params.get_as("iterations_part1"), &Budget::current()).map(|s| s.len())
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        look_and_say_within(&input.value, input.params.get_as("iterations_part2"), &Budget::current()).map(|s| s.len())
+    }
+}
+
+impl Generator for Day10 {
+    const DEFAULT_SIZE: usize = 10;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        rng.string_from("123", size)
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<String, ParseError> {
+    let input = input.trim();
+    match input.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+        Some((i, c)) => Err(ParseError::at_offset(input, i, format!("Expected digit, found '{}'", c.escape_default()))),
+        None => Ok(input.into()),
+    }
+}
+
+pub fn look_and_say(s: &str) -> String {
+    let mut ret = String::new();
+    let mut add_number = |n: u32, count: i32| {
+        ret.push_str(&count.to_string());
+        ret.push_str(&n.to_string());
+    };
+    let mut last_number: Option<u32> = None;
+    let mut last_count: i32 = 0;
+    for c in s.chars() {
+        assert!(c.is_numeric());
+        let i: u32 = c.to_digit(10).expect("Not a digit");
+        if let Some(n) = last_number {
+            if i == n {
+                last_count += 1;
+            } else {
+                add_number(n, last_count);
+                last_number = Some(i);
+                last_count = 1;
+            }
+        } else {
+            last_number = Some(i);
+            last_count = 1;
+        }
+    }
+    if let Some(n) = last_number {
+        add_number(n, last_count);
+    }
+    ret
+}
+
+pub fn look_and_say_times(s: &str, times: usize) -> String {
+    look_and_say_within(s, times, &Budget::new()).done().expect("Unlimited budget")
+}
+
+// The sequence grows by about a third every round, so many rounds take long. A step is a round.
+pub fn look_and_say_within(s: &str, times: usize, budget: &Budget) -> Outcome<String> {
+    let mut s: String = s.into();
+    for round in 0..times {
+        if budget.is_exhausted() {
+            return Outcome::Stopped { partial: None, steps: round as u64 };
+        }
+        s = look_and_say(&s);
+    }
+    Outcome::Done(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_look_and_say() {
+        assert_eq!(look_and_say(""), "");
+        assert_eq!(look_and_say("1"), "11");
+        assert_eq!(look_and_say("11"), "21");
+        assert_eq!(look_and_say("21"), "1211");
+        assert_eq!(look_and_say("1211"), "111221");
+        assert_eq!(look_and_say("111221"), "312211");
+    }
+
+    #[test]
+    fn test_look_and_say_within() {
+        assert_eq!(look_and_say_times("1", 5), "312211");
+        let cancelled = Budget::new();
+        cancelled.cancel();
+        assert_eq!(look_and_say_within("1", 5, &cancelled), Outcome::Stopped { partial: None, steps: 0 });
+        assert_eq!(look_and_say_within("1", 0, &cancelled), Outcome::Done("1".into()));
+    }
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(parse_input("1113222113\n"), Ok("1113222113".into()));
+        assert_eq!(parse_input("11a3").unwrap_err().column, 3);
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = Day10::generate(&mut Rng::new(seed), 10);
+            assert!(Day10::parse(&input).is_ok(), "{}", input);
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use aoc_common::{Budget, LOWERCASE, Generator, Outcome, ParseError, Rng, Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const NAME: &'static str = "corporate_policy";
+
+    type Input = String;
+    type Answer1 = Outcome<String>;
+    type Answer2 = Outcome<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        next_password_within(input, &Budget::current())
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        match Self::part1(input) {
+            Outcome::Done(first) => next_password_within(&first, &Budget::current()),
+            Outcome::Stopped { steps, .. } => Outcome::Stopped { partial: None, steps },
+        }
+    }
+}
+
+impl Generator for Day11 {
+    const DEFAULT_SIZE: usize = 8;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        rng.string_from(LOWERCASE, size.max(MIN_LENGTH))
+    }
+}
+
+// A straight and two pairs need at least five letters, as in "aabcc"
+const MIN_LENGTH: usize = 5;
+
+pub fn parse_input(input: &str) -> Result<String, ParseError> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(ParseError::at_offset(input, 0, "Expected a password"));
+    }
+    match input.char_indices().find(|(_, c)| !c.is_ascii_lowercase()) {
+        Some((i, c)) => Err(ParseError::at_offset(input, i, format!("Expected lowercase letter, found '{}'", c.escape_default()))),
+        None if input.len() < MIN_LENGTH => {
+            Err(ParseError::at_offset(input, input.len(), format!("Passwords shorter than {} letters are never valid", MIN_LENGTH)))
+        },
+        None => Ok(input.into()),
+    }
+}
+
+pub fn contains_straight(s: &str) -> bool {
+    let mut last_letter: char = '\0';
+    let mut straight_count = 0;
+    for c in s.chars() {
+        if c as u8 != (last_letter as u8) + 1 {
+            last_letter = c;
+            straight_count = 1;
+        } else {
+            straight_count += 1;
+            last_letter = c;
+            if straight_count == 3 { return true; }
+        }
+    }
+    false
+}
+
+pub fn contains_no_bad_letters(s: &str) -> bool {
+    s.find(['i', 'o', 'l']).is_none()
+}
+
+pub fn contains_pairs(s: &str) -> bool {
+    let mut last_letter: char = '\0';
+    let mut straight_count = 0;
+    let mut pair_count = 0;
+    for c in s.chars() {
+        if c == last_letter {
+            straight_count += 1;
+            if straight_count % 2 == 0 { pair_count += 1; }
+            if pair_count == 2 { return true; }
+        } else {
+            last_letter = c;
+            straight_count = 1;
+        }
+    }
+    false
+}
+
+pub fn is_valid_password(s: &str) -> bool {
+    contains_no_bad_letters(s) && contains_straight(s) && contains_pairs(s)
+}
+
+pub fn next_password_after(s: &str) -> String {
+    next_password_within(s, &Budget::new()).done().expect("Unlimited budget")
+}
+
+// Passwords with a bad letter near the front take long to get past it, a step is a password tried
+pub fn next_password_within(s: &str, budget: &Budget) -> Outcome<String> {
+    let mut v = Vec::from(s);
+    let mut tried = 0_u64;
+    loop {
+        tried += 1;
+        if tried.is_multiple_of(0x1000) && budget.is_exhausted() {
+            return Outcome::Stopped { partial: None, steps: tried };
+        }
+        for c in v.iter_mut().rev() {
+            if *c != b'z' {
+                *c += 1;
+                if *c == b'i' || *c == b'o' || *c == b'l' {
+                    *c += 1;
+                }
+                assert!(*c >= b'a' && *c <= b'z');
+                break;
+            } else {
+                *c = b'a';
+            }
+        }
+        let r = String::from_utf8(v.clone()).unwrap();
+        if is_valid_password(&r) {
+            return Outcome::Done(r);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contains_straight() {
+        assert!(!contains_straight(""));
+        assert!(contains_straight("abc"));
+        assert!(contains_straight("xabc"));
+        assert!(contains_straight("abcx"));
+        assert!(contains_straight("xabcx"));
+        assert!(contains_straight("xbcdx"));
+        assert!(contains_straight("xcdex"));
+        assert!(contains_straight("xxyzx"));
+        assert!(!contains_straight("xabdx"));
+        assert!(contains_straight("hijklmmn"));
+        assert!(!contains_straight("abbceffg"));
+    }
+
+    #[test]
+    fn test_contains_pairs() {
+        assert!(!contains_pairs(""));
+        assert!(!contains_pairs("aa"));
+        assert!(!contains_pairs("aaa"));
+        assert!(contains_pairs("aabb"));
+        assert!(contains_pairs("xaabb"));
+        assert!(contains_pairs("aabbx"));
+        assert!(contains_pairs("xaabbx"));
+        assert!(contains_pairs("xaaxbbx"));
+        assert!(contains_pairs("abbceffg"));
+        assert!(!contains_pairs("abbcegjk"));
+    }
+
+    #[test]
+    fn test_contains_no_bad_letters() {
+        assert!(contains_no_bad_letters(""));
+        assert!(contains_no_bad_letters("abcdefg"));
+        assert!(!contains_no_bad_letters("i"));
+        assert!(!contains_no_bad_letters("o"));
+        assert!(!contains_no_bad_letters("l"));
+        assert!(!contains_no_bad_letters("abcidef"));
+        assert!(!contains_no_bad_letters("abcodef"));
+        assert!(!contains_no_bad_letters("abcldef"));
+        assert!(!contains_no_bad_letters("hijklmmn"));
+    }
+
+    #[test]
+    fn test_is_valid_password() {
+        assert!(!is_valid_password(""));
+        assert!(!is_valid_password("hijklmmn"));
+        assert!(!is_valid_password("abbceffg"));
+        assert!(!is_valid_password("abbcegjk"));
+        assert!(is_valid_password("abcdffaa"));
+        assert!(is_valid_password("ghjaabcc"));
+    }
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(parse_input("abcdefgh\n"), Ok("abcdefgh".into()));
+        assert_eq!(parse_input("abcDefgh").unwrap_err().column, 4);
+        assert!(parse_input("").is_err());
+        assert_eq!(parse_input("abcd").unwrap_err().column, 5);
+        assert!(parse_input("abcde").is_ok());
+    }
+
+    #[test]
+    fn test_next_password_after() {
+        assert_eq!(next_password_after("abcdefgh"), "abcdffaa");
+        assert_eq!(next_password_after("aabcb"), "aabcc");
+        let cancelled = Budget::new();
+        cancelled.cancel();
+        assert_eq!(next_password_within("iaaaaaaa", &cancelled), Outcome::Stopped { partial: None, steps: 0x1000 });
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = Day11::generate(&mut Rng::new(seed), 8);
+            assert!(Day11::parse(&input).is_ok(), "{}", input);
+        }
+    }
+}
@@ -0,0 +1,363 @@
+use aoc_common::{lowercase_name, Generator, ParseError, Rng, Solution};
+use std::collections::HashMap;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const NAME: &'static str = "JSAbacusFramework_io";
+
+    type Input = JsonObject;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_json(input.trim())
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        sum_all_numbers(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        sum_all_except_red(input)
+    }
+}
+
+impl Generator for Day12 {
+    const DEFAULT_SIZE: usize = 5000;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // `size` is the number of leaf values
+        let mut remaining = size;
+        let mut values = Vec::new();
+        while remaining > 0 {
+            values.push(generate_value(rng, &mut remaining, 0));
+        }
+        format!("[{}]", values.join(","))
+    }
+}
+
+fn generate_value(rng: &mut Rng, remaining: &mut usize, depth: usize) -> String {
+    if depth >= 5 || *remaining <= 1 || rng.chance(0.4) {
+        *remaining = remaining.saturating_sub(1);
+        return if rng.chance(0.3) {
+            format!("\"{}\"", rng.choose(&["red", "green", "blue", "orange", "violet"]))
+        } else {
+            rng.range(-100..=200).to_string()
+        };
+    }
+    let mut children = Vec::new();
+    for _ in 0..rng.range(1..=10) {
+        if *remaining == 0 { break; }
+        children.push(generate_value(rng, remaining, depth + 1));
+    }
+    if rng.chance(0.5) {
+        format!("[{}]", children.join(","))
+    } else {
+        // Keys stay within a..j, so no key is ever "red"
+        let fields: Vec<_> = children.iter().enumerate().map(|(i, c)| format!("\"{}\":{}", lowercase_name(i), c)).collect();
+        format!("{{{}}}", fields.join(","))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum JsonObject {
+    String(String),
+    Number(i64),
+    Object(HashMap<String, JsonObject>),
+    Array(Vec<JsonObject>),
+}
+
+pub fn parse_number(s: &str) -> Option<(i64, &str)> {
+    let i_end = s.find(|c: char| !(c.is_ascii_digit() || c == '-')).unwrap_or(s.len());
+    if let Ok(i) = s[0..i_end].parse::<i64>() {
+        Some((i, &s[i_end..]))
+    } else {
+        None
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct JsonError<'a> {
+    pub at: &'a str,
+    pub message: String,
+}
+
+impl<'a> JsonError<'a> {
+    fn new(at: &'a str, message: impl Into<String>) -> Self {
+        JsonError { at, message: message.into() }
+    }
+}
+
+pub type JsonResult<'a, T> = Result<(T, &'a str), JsonError<'a>>;
+
+pub fn parse_string(s: &str) -> JsonResult<'_, String> {
+    if !s.starts_with('"') { return Err(JsonError::new(s, "Expected '\"'")); }
+    let s_end = s[1..].find('"').ok_or_else(|| JsonError::new(s, "Unterminated string"))? + 1;
+    Ok((String::from(&s[1..s_end]), &s[s_end + 1..]))
+}
+
+// Deeper documents are rejected instead of overflowing the stack
+pub const MAX_DEPTH: usize = 256;
+
+pub fn parse_array(s: &str) -> JsonResult<'_, Vec<JsonObject>> {
+    parse_array_impl(s, 0)
+}
+
+fn parse_array_impl(s: &str, depth: usize) -> JsonResult<'_, Vec<JsonObject>> {
+    let mut v = Vec::new();
+    let Some(mut it) = s.strip_prefix('[') else { return Err(JsonError::new(s, "Expected '['")); };
+    if let Some(rest) = it.strip_prefix(']') { return Ok((v, rest)); }
+    loop {
+        let (j, new_it) = parse_json_impl(it, depth + 1)?;
+        v.push(j);
+        if let Some(rest) = new_it.strip_prefix(']') {
+            // end of array
+            it = rest;
+            break;
+        } else if let Some(rest) = new_it.strip_prefix(',') {
+            it = rest;
+        } else if new_it.is_empty() {
+            return Err(JsonError::new(new_it, "Unexpected end of array"));
+        } else {
+            return Err(JsonError::new(new_it, "Expected ','"));
+        }
+    }
+    Ok((v, it))
+}
+
+pub fn parse_object(s: &str) -> JsonResult<'_, HashMap<String, JsonObject>> {
+    parse_object_impl(s, 0)
+}
+
+fn parse_object_impl(s: &str, depth: usize) -> JsonResult<'_, HashMap<String, JsonObject>> {
+    let mut m = HashMap::new();
+    let Some(mut it) = s.strip_prefix('{') else { return Err(JsonError::new(s, "Expected '{'")); };
+    if let Some(rest) = it.strip_prefix('}') { return Ok((m, rest)); }
+    loop {
+        let (key, it_v) = parse_string(it)?;
+        let Some(it_val) = it_v.strip_prefix(':') else { return Err(JsonError::new(it_v, "Expected ':'")); };
+        let (val, it_next) = parse_json_impl(it_val, depth + 1)?;
+        m.insert(key, val);
+        if let Some(rest) = it_next.strip_prefix('}') {
+            // end of object
+            it = rest;
+            break;
+        } else if let Some(rest) = it_next.strip_prefix(',') {
+            it = rest;
+        } else if it_next.is_empty() {
+            return Err(JsonError::new(it_next, "Unexpected end of object"));
+        } else {
+            return Err(JsonError::new(it_next, "Expected ','"));
+        }
+    }
+    Ok((m, it))
+}
+
+pub fn parse_json(s: &str) -> Result<JsonObject, ParseError> {
+    let to_parse_error = |e: JsonError| ParseError::at_offset(s, s.len() - e.at.len(), e.message);
+    let (r, rest) = parse_json_impl(s, 0).map_err(to_parse_error)?;
+    if !rest.is_empty() {
+        return Err(to_parse_error(JsonError::new(rest, "Unexpected trailing characters")));
+    }
+    Ok(r)
+}
+
+fn parse_json_impl(s: &str, depth: usize) -> JsonResult<'_, JsonObject> {
+    match s.chars().next() {
+        Some('{' | '[') if depth >= MAX_DEPTH => Err(JsonError::new(s, format!("Nested deeper than {} levels", MAX_DEPTH))),
+        Some('{') => {
+            let (o, rest) = parse_object_impl(s, depth)?;
+            Ok((JsonObject::Object(o), rest))
+        },
+        Some('[') => {
+            let (a, rest) = parse_array_impl(s, depth)?;
+            Ok((JsonObject::Array(a), rest))
+        },
+        Some('"') => {
+            let (s, rest) = parse_string(s)?;
+            Ok((JsonObject::String(s), rest))
+        },
+        Some(c) if c.is_ascii_digit() || c == '-' => {
+            let (i, rest) = parse_number(s).ok_or_else(|| JsonError::new(s, "Error parsing number"))?;
+            Ok((JsonObject::Number(i), rest))
+        },
+        Some(c) => Err(JsonError::new(s, format!("Unexpected character {}", c))),
+        _ => Err(JsonError::new(s, "Unexpected end of string")),
+    }
+}
+
+pub fn sum_all_numbers(j: &JsonObject) -> i64 {
+    match j {
+        JsonObject::Number(n) => *n,
+        JsonObject::String(_) => 0,
+        JsonObject::Array(v) =>
+            v.iter().fold(0, |acc, e| acc + sum_all_numbers(e)),
+        JsonObject::Object(m) => 
+            m.iter().fold(0, |acc, (_, v)| acc + sum_all_numbers(v)),
+    }
+}
+
+pub fn object_has_red(obj: &HashMap<String, JsonObject>) -> bool {
+    for v in obj.values() {
+        if let JsonObject::String(s) = v { if s == "red" { return true; } };
+    }
+    false
+}
+
+pub fn sum_all_except_red(j: &JsonObject) -> i64 {
+    match j {
+        JsonObject::Number(n) => *n,
+        JsonObject::String(_) => 0,
+        JsonObject::Array(v) =>
+            v.iter().fold(0, |acc, e| acc + sum_all_except_red(e)),
+        JsonObject::Object(m) => 
+            if object_has_red(m) { 0 } else { m.iter().fold(0, |acc, (_, v)| acc + sum_all_except_red(v)) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_json() {
+        assert_eq!(parse_json("5").unwrap(), JsonObject::Number(5));
+        assert_eq!(parse_json("-42").unwrap(), JsonObject::Number(-42));
+        assert_eq!(parse_json(r#""a""#).unwrap(), JsonObject::String("a".to_string()));
+        assert_eq!(parse_json(r#""foo""#).unwrap(), JsonObject::String("foo".to_string()));
+        assert_eq!(parse_json(r#""foo bar baz""#).unwrap(), JsonObject::String("foo bar baz".to_string()));
+        assert_eq!(parse_json(r#"{"a":2,"b":4}"#).unwrap(), JsonObject::Object(
+            HashMap::from([
+                ("a".into(), JsonObject::Number(2)),
+                ("b".into(), JsonObject::Number(4)),
+            ])
+        ));
+        assert_eq!(parse_json(r#"[[[3]]]"#).unwrap(), JsonObject::Array(
+            vec![
+                JsonObject::Array(vec![
+                    JsonObject::Array(vec![JsonObject::Number(3)]),
+                ])],
+        ));
+        assert_eq!(parse_json(r#"{"a":{"b":4},"c":-1}"#).unwrap(), JsonObject::Object(
+            HashMap::from([
+                ("a".into(), JsonObject::Object(
+                    HashMap::from([("b".into(), JsonObject::Number(4))])
+                )),
+                ("c".into(), JsonObject::Number(-1)),
+            ])
+        ));
+    }
+
+    #[test]
+    fn test_parse_json_errors() {
+        let e = parse_json(r#"{"a":[1,2}"#).unwrap_err();
+        assert_eq!((e.line, e.column), (1, 10));
+        let e = parse_json(r#"[1,2"#).unwrap_err();
+        assert_eq!((e.line, e.column), (1, 5));
+        let e = parse_json(r#"{"a" 1}"#).unwrap_err();
+        assert_eq!((e.line, e.column), (1, 5));
+        let e = parse_json(r#"[1]]"#).unwrap_err();
+        assert_eq!((e.line, e.column), (1, 4));
+        assert!(parse_json("").is_err());
+        assert!(parse_json(r#"{"a"#).is_err());
+        assert!(parse_json("[-]").is_err());
+    }
+
+    #[test]
+    fn test_nesting_limit() {
+        let nested = |depth: usize| "[".repeat(depth) + &"]".repeat(depth);
+        assert!(parse_json(&nested(MAX_DEPTH)).is_ok());
+        let e = parse_json(&nested(MAX_DEPTH + 1)).unwrap_err();
+        assert_eq!((e.column, e.message.as_str()), (MAX_DEPTH + 1, "Nested deeper than 256 levels"));
+        assert!(parse_json(&"{\"a\":".repeat(100_000)).is_err());
+    }
+
+    #[test]
+    fn test_parse_number() {
+        assert_eq!(parse_number(""), None);
+        assert_eq!(parse_number("5"), Some((5, "")));
+        assert_eq!(parse_number("12"), Some((12, "")));
+        assert_eq!(parse_number("-12"), Some((-12, "")));
+        assert_eq!(parse_number("-12a"), Some((-12, "a")));
+        assert_eq!(parse_number("-12aaa"), Some((-12, "aaa")));
+        assert_eq!(parse_number("-a12"), None);
+    }
+
+    #[test]
+    fn test_parse_string() {
+        assert_eq!(parse_string(r#""""#).unwrap(), ("".to_string(), ""));
+        assert_eq!(parse_string(r#"""""#).unwrap(), ("".to_string(), "\""));
+        assert_eq!(parse_string(r#""a"b"#).unwrap(), ("a".to_string(), "b"));
+        assert_eq!(parse_string(r#""aaa bbb"xyz"#).unwrap(), ("aaa bbb".to_string(), "xyz"));
+    }
+
+    #[test]
+    fn test_parse_array() {
+        assert_eq!(parse_array("[]").unwrap(), (vec![], ""));
+        assert_eq!(parse_array("[1,2,3]").unwrap(), (vec![JsonObject::Number(1), JsonObject::Number(2), JsonObject::Number(3)], ""));
+        assert_eq!(parse_array(r#"[1,"foo",3]"#).unwrap(), (vec![JsonObject::Number(1), JsonObject::String("foo".to_string()), JsonObject::Number(3)], ""));
+    }
+
+    #[test]
+    fn test_parse_object() {
+        assert_eq!(parse_object(r#"{}"#).unwrap(), (HashMap::from([]), ""));
+        assert_eq!(parse_object(r#"{"foo":1,"bar":2}"#).unwrap(), (HashMap::from([
+            ("foo".into(), JsonObject::Number(1)),
+            ("bar".into(), JsonObject::Number(2)),
+        ]), ""));
+        assert_eq!(parse_object(r#"{"foo":1,"bar":"baz"}"#).unwrap(), (HashMap::from([
+            ("foo".into(), JsonObject::Number(1)),
+            ("bar".into(), JsonObject::String("baz".into())),
+        ]), ""));
+        assert_eq!(parse_object(r#"{"foo":1,"bar":[2,3]}aaa"#).unwrap(), (HashMap::from([
+            ("foo".into(), JsonObject::Number(1)),
+            ("bar".into(), JsonObject::Array(vec![JsonObject::Number(2), JsonObject::Number(3)])),
+        ]), "aaa"));
+    }
+
+    #[test]
+    fn test_sum_all_numbers() {
+        assert_eq!(sum_all_numbers(&parse_json(r#"[1,2,3]"#).unwrap()), 6);
+        assert_eq!(sum_all_numbers(&parse_json(r#"{"a":2,"b":4}"#).unwrap()), 6);
+        assert_eq!(sum_all_numbers(&parse_json(r#"[[[3]]]"#).unwrap()), 3);
+        assert_eq!(sum_all_numbers(&parse_json(r#"{"a":{"b":4},"c":-1}"#).unwrap()), 3);
+        assert_eq!(sum_all_numbers(&parse_json(r#"{"a":[-1,1]}"#).unwrap()), 0);
+        assert_eq!(sum_all_numbers(&parse_json(r#"[-1,{"a":1}]"#).unwrap()), 0);
+        assert_eq!(sum_all_numbers(&parse_json(r#"[]"#).unwrap()), 0);
+        assert_eq!(sum_all_numbers(&parse_json(r#"{}"#).unwrap()), 0);
+    }
+
+    #[test]
+    fn test_object_has_red() {
+        assert!(!object_has_red(&HashMap::from([])));
+        assert!(!object_has_red(&HashMap::from([
+            ("a".into(), JsonObject::Number(1)),
+            ("b".into(), JsonObject::String("blue".into())),
+            ("c".into(), JsonObject::Array(vec![JsonObject::String("red".into())])),
+        ])));
+        assert!(object_has_red(&HashMap::from([
+            ("a".into(), JsonObject::Number(1)),
+            ("b".into(), JsonObject::String("red".into())),
+            ("c".into(), JsonObject::Array(vec![JsonObject::Number(1)])),
+        ])));
+    }
+
+    #[test]
+    fn test_sum_all_except_red() {
+        assert_eq!(sum_all_except_red(&parse_json(r#"[1,2,3]"#).unwrap()), 6);
+        assert_eq!(sum_all_except_red(&parse_json(r#"[1,{"c":"red","b":2},3]"#).unwrap()), 4);
+        assert_eq!(sum_all_except_red(&parse_json(r#"{"d":"red","e":[1,2,3,4],"f":5}"#).unwrap()), 0);
+        assert_eq!(sum_all_except_red(&parse_json(r#"[1,"red",5]"#).unwrap()), 6);
+        assert_eq!(sum_all_except_red(&parse_json(r#"{"red":1}"#).unwrap()), 1);
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = Day12::generate(&mut Rng::new(seed), 100);
+            assert!(Day12::parse(&input).is_ok(), "{}", input);
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use aoc_common::{line_struct, parse_lines, Budget, Example, FromLine, Generator, Outcome, ParseError, Rng, Solution};
+use aoc_tour::{best_tour, best_tour_within, Objective, Shape, Tour};
+use std::collections::HashMap;
+
+pub struct Day13;
+
+// From the puzzle description
+const SAMPLE: &str = "Alice would gain 54 happiness units by sitting next to Bob.
+Alice would lose 79 happiness units by sitting next to Carol.
+Alice would lose 2 happiness units by sitting next to David.
+Bob would gain 83 happiness units by sitting next to Alice.
+Bob would lose 7 happiness units by sitting next to Carol.
+Bob would lose 63 happiness units by sitting next to David.
+Carol would lose 62 happiness units by sitting next to Alice.
+Carol would gain 60 happiness units by sitting next to Bob.
+Carol would gain 55 happiness units by sitting next to David.
+David would gain 46 happiness units by sitting next to Alice.
+David would lose 7 happiness units by sitting next to Bob.
+David would gain 41 happiness units by sitting next to Carol.
+";
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const NAME: &'static str = "knights_of_the_dinner_table";
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(SAMPLE).part1("330"),
+    ];
+
+    type Input = Input;
+    type Answer1 = Outcome<i64>;
+    type Answer2 = Outcome<i64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        find_optimal_arrangement_within(input, &Budget::current())
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        let mut inp = input.clone();
+        inp.names.push("Me".into());
+        find_optimal_arrangement_within(&inp, &Budget::current())
+    }
+}
+
+impl Generator for Day13 {
+    const DEFAULT_SIZE: usize = 8;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut r = String::new();
+        for person in 0..size {
+            for neighbor in (0..size).filter(|&n| n != person) {
+                let gain_lose = if rng.chance(0.5) { "gain" } else { "lose" };
+                r += &format!("Guest{} would {} {} happiness units by sitting next to Guest{}.\n",
+                              person, gain_lose, rng.range(0..=100), neighbor);
+            }
+        }
+        r
+    }
+}
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub struct Seat {
+    pub person: usize,
+    pub next_to: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub names: Vec<String>,
+    pub constraints: HashMap<Seat, i64>,
+}
+
+line_struct! {
+    pub struct Preference = "{} would {} {} happiness units by sitting next to {}." {
+        pub person: String,
+        pub sign: i64 = parse_sign,
+        pub amount: i64,
+        pub neighbor: String,
+    }
+}
+
+fn parse_sign(line: &str, part: &str) -> Result<i64, ParseError> {
+    match part {
+        "gain" => Ok(1),
+        "lose" => Ok(-1),
+        _ => Err(ParseError::new(line, part, "Expected 'gain' or 'lose'")),
+    }
+}
+
+pub fn parse_input(s: &str) -> Result<Input, ParseError> {
+    let mut names = Vec::<String>::new();
+    let mut names_map = HashMap::<String, usize>::new();
+    let mut id = |name: String| *names_map.entry(name.clone()).or_insert_with(|| { names.push(name); names.len() - 1 });
+    let mut constraints = HashMap::new();
+    for p in parse_lines(s, Preference::from_line)? {
+        let seat = Seat { person: id(p.person), next_to: id(p.neighbor) };
+        constraints.insert(seat, p.sign * p.amount);
+    }
+    if names.is_empty() {
+        return Err(ParseError::at_offset(s, s.len(), "Expected at least one guest"));
+    }
+    Ok(Input { names, constraints })
+}
+
+pub fn evaluate_arrangement(inp: &Input, arrangement: &[usize]) -> i64 {
+    let mut next = arrangement[arrangement.len() - 1];
+    let mut acc = 0;
+    for &i in arrangement {
+        if let Some(&v) = inp.constraints.get(&Seat{ person: i, next_to: next }) {
+            acc += v;
+        }
+        if let Some(&v) = inp.constraints.get(&Seat{ person: next, next_to: i }) {
+            acc += v;
+        }
+        next = i;
+    }
+    acc
+}
+
+// Happiness change for `a` and `b` sitting next to each other, people without constraints don't care
+pub fn pair_happiness(inp: &Input, a: usize, b: usize) -> i64 {
+    let get = |person, next_to| inp.constraints.get(&Seat { person, next_to }).copied().unwrap_or(0);
+    get(a, b) + get(b, a)
+}
+
+pub fn best_arrangement(inp: &Input) -> Option<Tour> {
+    best_tour(inp.names.len(), Shape::Cycle, Objective::Maximize, |a, b| Some(pair_happiness(inp, a, b)))
+}
+
+pub fn find_optimal_arrangement(inp: &Input) -> i64 {
+    best_arrangement(inp).expect("Need at least one guest").cost
+}
+
+// The happiness of the best arrangement tried so far when the budget runs out
+pub fn find_optimal_arrangement_within(inp: &Input, budget: &Budget) -> Outcome<i64> {
+    best_tour_within(inp.names.len(), Shape::Cycle, Objective::Maximize, budget, |a, b| Some(pair_happiness(inp, a, b)))
+        .expect("Need at least one guest")
+        .map(|t| t.cost)
+}
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        let input = parse_input(SAMPLE).unwrap();
+        assert_eq!(input.names, vec!["Alice", "Bob", "Carol", "David"]);
+        assert_eq!(input.constraints, HashMap::from([
+            (Seat{ person: 0, next_to: 1 }, 54),
+            (Seat{ person: 0, next_to: 2 }, -79),
+            (Seat{ person: 0, next_to: 3 }, -2),
+            (Seat{ person: 1, next_to: 0 }, 83),
+            (Seat{ person: 1, next_to: 2 }, -7),
+            (Seat{ person: 1, next_to: 3 }, -63),
+            (Seat{ person: 2, next_to: 0 }, -62),
+            (Seat{ person: 2, next_to: 1 }, 60),
+            (Seat{ person: 2, next_to: 3 }, 55),
+            (Seat{ person: 3, next_to: 0 }, 46),
+            (Seat{ person: 3, next_to: 1 }, -7),
+            (Seat{ person: 3, next_to: 2 }, 41),
+        ]));
+    }
+
+    #[test]
+    fn test_parse_input_errors() {
+        let e = parse_input("Alice would gain 54 happiness units by sitting next to Bob.\nBob would win 3 happiness units by sitting next to Alice.").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 11));
+        assert!(parse_input("Alice would gain 99999999999999999999 happiness units by sitting next to Bob.").is_err());
+        assert_eq!(parse_input("").unwrap_err().message, "Expected at least one guest");
+    }
+
+    #[test]
+    fn test_evaluate_arrangement() {
+        let input = parse_input(SAMPLE).unwrap();
+        let arrangement = vec![0, 1, 2, 3];
+        assert_eq!(evaluate_arrangement(&input, &arrangement), 330);
+    }
+
+    #[test]
+    fn test_find_optimal_arrangement() {
+        let input = parse_input(SAMPLE).unwrap();
+        assert_eq!(find_optimal_arrangement(&input), 330);
+        let t = best_arrangement(&input).unwrap();
+        assert_eq!(evaluate_arrangement(&input, &t.order), 330);
+    }
+
+    #[test]
+    fn test_find_optimal_arrangement_budget() {
+        let input = parse_input(SAMPLE).unwrap();
+        let cancelled = Budget::new();
+        cancelled.cancel();
+        assert_eq!(find_optimal_arrangement_within(&input, &cancelled), Outcome::Done(330));
+        let large = parse_input(&Day13::generate(&mut Rng::new(1), 12)).unwrap();
+        assert!(matches!(find_optimal_arrangement_within(&large, &cancelled), Outcome::Stopped { partial: Some(_), .. }));
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc_common::check_examples::<Day13>();
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = Day13::generate(&mut Rng::new(seed), 5);
+            assert!(Day13::parse(&input).is_ok(), "{}", input);
+        }
+    }
+}
@@ -0,0 +1,252 @@
+use aoc_common::visual::{self, Frame};
+use aoc_common::{line_struct, parse_lines, Configured, Example, FromLine, Generator, Param, Params, ParseError, Rng, Solution};
+
+pub struct Day14;
+
+// From the puzzle description
+const SAMPLE: &str = r"Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.
+Dancer can fly 16 km/s for 11 seconds, but then must rest for 162 seconds.
+    ";
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const NAME: &'static str = "reindeer_olympics";
+
+    const PARAMS: &'static [Param] = &[
+        Param::new("seconds", 2503, "Length of the race in seconds").range(0, u32::MAX as i64),
+    ];
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(SAMPLE).with_params(&[("seconds", 1000)]).part1("1120").part2("689"),
+    ];
+
+    type Input = Configured<Vec<Reindeer>>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, ParseError> {
+        Ok(Configured { value: parse_input(input)?, params: params.clone() })
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        winner_after(&input.value, input.params.get("seconds"))
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        new_score(&input.value, input.params.get("seconds"))
+    }
+}
+
+impl Generator for Day14 {
+    const DEFAULT_SIZE: usize = 9;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|i| format!("Reindeer{} can fly {} km/s for {} seconds, but then must rest for {} seconds.\n",
+                             i, rng.range(1..=30), rng.range(1..=20), rng.range(1..=200)))
+            .collect()
+    }
+}
+
+// Keeps the distances of races as long as the `seconds` parameter allows within an i64
+const MAX_STAT: i64 = i32::MAX as i64;
+
+line_struct! {
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct Reindeer = "{} can fly {} km/s for {} seconds, but then must rest for {} seconds." {
+        pub name: String,
+        pub speed: i64 where |s| (0..=MAX_STAT).contains(s) => format!("Speed must be from 0 to {}", MAX_STAT),
+        pub travel_time: i64 where |t| (1..=MAX_STAT).contains(t) => format!("Travel time must be from 1 to {}", MAX_STAT),
+        pub rest_time: i64 where |t| (0..=MAX_STAT).contains(t) => format!("Rest time must be from 0 to {}", MAX_STAT),
+    }
+}
+
+pub fn parse_input(s: &str) -> Result<Vec<Reindeer>, ParseError> {
+    let ret = parse_lines(s.trim(), Reindeer::from_line)?;
+    if ret.is_empty() {
+        return Err(ParseError::new(s, s, "No reindeer in input"));
+    }
+    Ok(ret)
+}
+
+pub fn calculate_position_at_time(r: &Reindeer, t: i64) -> i64 {
+    let period = r.travel_time + r.rest_time;
+    let full_distances = t / period;
+    let remainder = t % period;
+    let partial_distance = std::cmp::min(remainder, r.travel_time);
+    (full_distances * r.travel_time + partial_distance) * r.speed
+}
+
+pub fn winner_after(rs: &[Reindeer], t: i64) -> i64 {
+    rs.iter()
+        .map(|r| calculate_position_at_time(r, t))
+        .max()
+        .unwrap()
+}
+
+pub fn new_score(rs: &[Reindeer], t: i64) -> i64 {
+    let mut scoreboard = vec![0_i64; rs.len()];
+    for i in 0..t {
+        let i = i + 1;
+        let winning_score = winner_after(rs, i);
+        for (idx, r) in scoreboard.iter_mut().enumerate() {
+            if calculate_position_at_time(&rs[idx], i) == winning_score {
+                *r += 1;
+            }
+        }
+        if i % 10 == 0 || i == t {
+            show_race(rs, &scoreboard, i, t);
+        }
+    }
+    scoreboard.into_iter().max().unwrap()
+}
+
+// Bars are scaled to the distance of the winner at the end of the race
+fn show_race(rs: &[Reindeer], scoreboard: &[i64], time: i64, race_length: i64) {
+    visual::show(|| {
+        const BAR_WIDTH: i64 = 60;
+        let finish = winner_after(rs, race_length).max(1);
+        let name_width = rs.iter().map(|r| r.name.len()).max().unwrap_or(0);
+        let lines = rs.iter().zip(scoreboard).map(|(r, points)| {
+            let distance = calculate_position_at_time(r, time);
+            let bar = "=".repeat((distance * BAR_WIDTH / finish) as usize) + ">";
+            format!("{:<w$} {:<b$} {:>6} km {:>5} points", r.name, bar, distance, points, w = name_width, b = BAR_WIDTH as usize + 1)
+        });
+        Frame { title: format!("Second {}/{}", time, race_length), lines: lines.collect() }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        let rs = parse_input(SAMPLE).unwrap();
+        assert_eq!(rs, vec![
+            Reindeer { name: "Comet".into(), speed: 14, travel_time: 10, rest_time: 127 },
+            Reindeer { name: "Dancer".into(), speed: 16, travel_time: 11, rest_time: 162 },
+        ]);
+    }
+
+    #[test]
+    fn test_parse_input_errors() {
+        let e = parse_input("Comet can fly 14 km/s for 0 seconds, but then must rest for 127 seconds.").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 27));
+        let e = parse_input("Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.\nDancer can fly").unwrap_err();
+        assert_eq!(e.line, 2);
+        assert!(parse_input("").is_err());
+        let e = parse_input("Comet can fly 14 km/s for 10 seconds, but then must rest for -10 seconds.").unwrap_err();
+        assert_eq!((e.column, e.message.as_str()), (62, "Rest time must be from 0 to 2147483647"));
+        assert!(parse_input("Comet can fly 4294967296 km/s for 10 seconds, but then must rest for 10 seconds.").is_err());
+    }
+
+    #[test]
+    fn test_calculate_position_at_time() {
+        let rs = parse_input(SAMPLE).unwrap();
+        assert_eq!(calculate_position_at_time(&rs[0], 1), 14);
+        assert_eq!(calculate_position_at_time(&rs[1], 1), 16);
+        assert_eq!(calculate_position_at_time(&rs[0], 10), 140);
+        assert_eq!(calculate_position_at_time(&rs[1], 10), 160);
+        assert_eq!(calculate_position_at_time(&rs[0], 11), 140);
+        assert_eq!(calculate_position_at_time(&rs[1], 11), 176);
+        assert_eq!(calculate_position_at_time(&rs[0], 12), 140);
+        assert_eq!(calculate_position_at_time(&rs[1], 12), 176);
+        assert_eq!(calculate_position_at_time(&rs[0], 138), 154);
+        assert_eq!(calculate_position_at_time(&rs[1], 138), 176);
+        assert_eq!(calculate_position_at_time(&rs[0], 174), 280);
+        assert_eq!(calculate_position_at_time(&rs[1], 174), 192);
+        assert_eq!(calculate_position_at_time(&rs[0], 1000), 1120);
+        assert_eq!(calculate_position_at_time(&rs[1], 1000), 1056);
+    }
+    
+    // Second by second: flying until the travel time is used up, then resting for the full rest time
+    fn naive_positions(r: &Reindeer, t: i64) -> Vec<i64> {
+        let (mut position, mut flying, mut left) = (0, true, r.travel_time);
+        let mut positions = vec![0];
+        for _ in 0..t {
+            if flying {
+                position += r.speed;
+            }
+            left -= 1;
+            if left == 0 {
+                flying = !flying;
+                left = if flying { r.travel_time } else { r.rest_time };
+            }
+            positions.push(position);
+        }
+        positions
+    }
+
+    // One point per second for every reindeer in the lead, from the naive positions
+    fn naive_score(rs: &[Reindeer], t: i64) -> i64 {
+        let positions: Vec<_> = rs.iter().map(|r| naive_positions(r, t)).collect();
+        let mut points = vec![0; rs.len()];
+        for second in 1..=t as usize {
+            let lead = positions.iter().map(|p| p[second]).max().unwrap();
+            for (i, p) in positions.iter().enumerate() {
+                if p[second] == lead {
+                    points[i] += 1;
+                }
+            }
+        }
+        points.into_iter().max().unwrap()
+    }
+
+    fn generate_race(rng: &mut Rng) -> (Vec<Reindeer>, i64) {
+        let size = rng.range(1..=5) as usize;
+        (parse_input(&Day14::generate(rng, size)).unwrap(), rng.range(0..=1000))
+    }
+
+    #[test]
+    fn test_calculate_position_at_time_reference() {
+        let fast = |(rs, t): &(Vec<Reindeer>, i64)| {
+            rs.iter().map(|r| (0..=*t).map(|s| calculate_position_at_time(r, s)).collect()).collect::<Vec<Vec<_>>>()
+        };
+        let reference = |(rs, t): &(Vec<Reindeer>, i64)| rs.iter().map(|r| naive_positions(r, *t)).collect::<Vec<_>>();
+        aoc_common::check_against_reference(200, generate_race, fast, reference);
+    }
+
+    #[test]
+    fn test_new_score_reference() {
+        aoc_common::check_against_reference(100, generate_race, |(rs, t)| new_score(rs, *t), |(rs, t)| naive_score(rs, *t));
+    }
+
+    #[test]
+    fn test_winner_after() {
+        let rs = parse_input(SAMPLE).unwrap();
+        assert_eq!(winner_after(&rs, 1000), 1120);
+    }
+    
+    #[test]
+    fn test_new_score() {
+        let rs = parse_input(SAMPLE).unwrap();
+        assert_eq!(new_score(&rs, 1000), 689);
+    }
+
+    #[test]
+    fn test_race_length() {
+        let mut params = Params::defaults(Day14::PARAMS);
+        params.set("seconds", 1000).unwrap();
+        let race = Day14::parse_with(SAMPLE, &params).unwrap();
+        assert_eq!((Day14::part1(&race), Day14::part2(&race)), (1120, 689));
+        assert_eq!(Day14::parse(SAMPLE).unwrap().params.get("seconds"), 2503);
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc_common::check_examples::<Day14>();
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = Day14::generate(&mut Rng::new(seed), 10);
+            assert!(Day14::parse(&input).is_ok(), "{}", input);
+        }
+    }
+}
@@ -0,0 +1,222 @@
+use aoc_common::{line_struct, parse_lines, Configured, Example, FromLine, Generator, Param, Params, ParseError, Rng, Solution};
+
+pub struct Day15;
+
+// From the puzzle description
+const SAMPLE: &str = r"Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8
+Cinnamon: capacity 2, durability 3, flavor -2, texture -1, calories 3
+";
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const NAME: &'static str = "science_for_hungry_people";
+
+    const PARAMS: &'static [Param] = &[
+        Param::new("teaspoons", 100, "Total amount of ingredients in a cookie").range(0, u16::MAX as i64),
+        Param::new("calories", 500, "Calories of the cookies in part 2"),
+    ];
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(SAMPLE).part1("62842880").part2("57600000"),
+    ];
+
+    type Input = Configured<Vec<Ingredient>>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, ParseError> {
+        Ok(Configured { value: parse_input(input)?, params: params.clone() })
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        highest_score(&input.value, input.params.get("teaspoons"))
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        highest_score_with_calories(&input.value, input.params.get("teaspoons"), input.params.get("calories"))
+    }
+}
+
+impl Generator for Day15 {
+    const DEFAULT_SIZE: usize = 4;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        (0..size)
+            .map(|i| format!("Ingredient{}: capacity {}, durability {}, flavor {}, texture {}, calories {}\n",
+                             i, rng.range(-5..=5), rng.range(-5..=5), rng.range(-5..=5), rng.range(-5..=5), rng.range(1..=10)))
+            .collect()
+    }
+}
+
+line_struct! {
+    #[derive(Debug, PartialEq, Default)]
+    pub struct Ingredient = "{}: capacity {}, durability {}, flavor {}, texture {}, calories {}" {
+        pub name: String,
+        pub capacity: i64,
+        pub durability: i64,
+        pub flavor: i64,
+        pub texture: i64,
+        pub calories: i64,
+    }
+}
+
+impl std::ops::Add for &Ingredient {
+    type Output = Ingredient;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Ingredient {
+            name: String::new(),
+            capacity: self.capacity + rhs.capacity,
+            durability: self.durability + rhs.durability,
+            flavor: self.flavor + rhs.flavor,
+            texture: self.texture + rhs.texture,
+            calories: 0,
+        }
+    }
+}
+
+impl std::ops::Mul<i64> for &Ingredient {
+    type Output = Ingredient;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Ingredient{
+            name: String::new(),
+            capacity: self.capacity * rhs,
+            durability: self.durability * rhs,
+            flavor: self.flavor * rhs,
+            texture: self.texture * rhs,
+            calories: 0,
+        }
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Ingredient>, ParseError> {
+    let ret = parse_lines(input, Ingredient::from_line)?;
+    if ret.is_empty() {
+        return Err(ParseError::new(input, input, "No ingredients in input"));
+    }
+    Ok(ret)
+}
+
+pub fn calculate_score(ingredients: &[Ingredient], amounts: &[i64]) -> i64 {
+    assert_eq!(ingredients.len(), amounts.len());
+    let mut acc = ingredients.iter()
+        .zip(amounts)
+        .fold(Ingredient::default(), |acc, (ingredient, &amount)|
+            &acc + &(ingredient * amount)
+        );
+    acc.capacity = std::cmp::max(acc.capacity, 0);
+    acc.durability = std::cmp::max(acc.durability, 0);
+    acc.flavor = std::cmp::max(acc.flavor, 0);
+    acc.texture = std::cmp::max(acc.texture, 0);
+    acc.capacity * acc.durability * acc.flavor * acc.texture
+}
+
+pub fn calculate_calories(ingredients: &[Ingredient], amounts: &[i64]) -> i64 {
+    ingredients.iter()
+        .zip(amounts)
+        .fold(0_i64, |acc, (ingredient, amount)| 
+            acc + (ingredient.calories * amount)
+        )
+}
+
+fn enumerate_amounts_rec<F>(amounts: &mut [i64], index: usize, budget: i64, func: &mut F)
+    where F: FnMut(&[i64])
+{
+    if index < amounts.len() - 1 {
+        for i in 0..budget {
+            amounts[index] = i;
+            enumerate_amounts_rec(amounts, index + 1, budget - i, func);
+        }
+    } else {
+        amounts[index] = budget;
+        func(amounts);
+    }
+}
+
+pub fn highest_score(ingredients: &[Ingredient], teaspoons: i64) -> i64 {
+    let mut amounts = vec![0; ingredients.len()];
+    let mut max_score = -1;
+    enumerate_amounts_rec(&mut amounts, 0, teaspoons, &mut |amounts: &[i64]| {
+        let score = calculate_score(ingredients, amounts);
+        max_score = std::cmp::max(max_score, score);
+    });
+    max_score
+}
+
+pub fn highest_score_with_calories(ingredients: &[Ingredient], teaspoons: i64, calories: i64) -> i64 {
+    let mut amounts = vec![0; ingredients.len()];
+    let mut max_score = -1;
+    enumerate_amounts_rec(&mut amounts, 0, teaspoons, &mut |amounts: &[i64]| {
+        if calculate_calories(ingredients, amounts) == calories {
+            let score = calculate_score(ingredients, amounts);
+            max_score = std::cmp::max(max_score, score);
+        }
+    });
+    max_score
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(parse_input(SAMPLE).unwrap(), vec![
+            Ingredient{ name: "Butterscotch".into(), capacity: -1, durability: -2, flavor: 6, texture: 3, calories: 8 },
+            Ingredient{ name: "Cinnamon".into(), capacity: 2, durability: 3, flavor: -2, texture: -1, calories: 3 },
+        ]);
+    }
+
+    #[test]
+    fn test_parse_input_errors() {
+        let e = parse_input("Butterscotch: capacity -1, durability --2, flavor 6, texture 3, calories 8").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 39));
+        let e = parse_input("Butterscotch: capacity -1\n").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 1));
+        assert!(parse_input("").is_err());
+    }
+
+     #[test]
+    fn test_calculate_score() {
+        let ingredients = parse_input(SAMPLE).unwrap();
+        assert_eq!(calculate_score(&ingredients, &[44, 56]), 62842880);
+    }
+
+    #[test]
+    fn test_highest_score() {
+        let ingredients = parse_input(SAMPLE).unwrap();
+        assert_eq!(highest_score(&ingredients, 100), 62842880);
+    }
+    
+    #[test]
+    fn test_calculate_calories() {
+        let ingredients = parse_input(SAMPLE).unwrap();
+        assert_eq!(calculate_calories(&ingredients, &[40, 60]), 500);
+    }
+
+    #[test]
+    fn test_highest_score_with_calories() {
+        let ingredients = parse_input(SAMPLE).unwrap();
+        assert_eq!(highest_score_with_calories(&ingredients, 100, 500), 57600000);
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc_common::check_examples::<Day15>();
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = Day15::generate(&mut Rng::new(seed), 3);
+            assert!(Day15::parse(&input).is_ok(), "{}", input);
+        }
+    }
+}
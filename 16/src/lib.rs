@@ -0,0 +1,145 @@
+use aoc_common::{line_struct, parse_lines, parse_value, FromLine, Generator, ParseError, Rng, Solution};
+use std::collections::HashMap;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const NAME: &'static str = "aunt_sue";
+
+    type Input = Vec<Aunt>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        find_best_match(input, &profile(), match_score) + 1
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        find_best_match(input, &profile(), match_score2) + 1
+    }
+}
+
+impl Generator for Day16 {
+    const DEFAULT_SIZE: usize = 500;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        let mut things: Vec<_> = profile().things.into_keys().collect();
+        things.sort();
+        let mut r = String::new();
+        for i in 0..size {
+            rng.shuffle(&mut things);
+            r += &format!("Sue {}: {}: {}, {}: {}, {}: {}\n", i + 1,
+                          things[0], rng.range(0..=10), things[1], rng.range(0..=10), things[2], rng.range(0..=10));
+        }
+        r
+    }
+}
+
+pub fn profile() -> Aunt {
+    let mut m = HashMap::<String, i64>::new();
+    m.insert("children".into(), 3);
+    m.insert("cats".into(), 7);
+    m.insert("samoyeds".into(), 2);
+    m.insert("pomeranians".into(), 3);
+    m.insert("akitas".into(), 0);
+    m.insert("vizslas".into(), 0);
+    m.insert("goldfish".into(), 5);
+    m.insert("trees".into(), 3);
+    m.insert("cars".into(), 2);
+    m.insert("perfumes".into(), 1);
+    Aunt { number: 0, things: m }
+}
+
+line_struct! {
+    #[derive(Debug)]
+    pub struct Aunt = "Sue {}: {}" {
+        pub number: usize,
+        pub things: HashMap<String, i64> = parse_things,
+    }
+}
+
+// Comma separated list of `thing: count`
+fn parse_things(line: &str, part: &str) -> Result<HashMap<String, i64>, ParseError> {
+    let mut things = HashMap::new();
+    for thing in part.split(", ") {
+        let Some((name, count)) = thing.split_once(": ") else {
+            return Err(ParseError::new(line, thing, "Expected '<thing>: <count>'"));
+        };
+        things.insert(name.into(), parse_value(line, count)?);
+    }
+    Ok(things)
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Aunt>, ParseError> {
+    let v = parse_lines(input, Aunt::from_line)?;
+    if v.is_empty() {
+        return Err(ParseError::new(input, input, "No aunts in input"));
+    }
+    Ok(v)
+}
+
+pub fn match_score(aunt: &Aunt, profile: &Aunt) -> i64 {
+    let mut score = 0;
+    for (k, &v) in &profile.things {
+        if let Some(&x) = aunt.things.get(k) {
+            if x == v { score += 1; }
+        }
+    }
+    score
+}
+
+pub fn match_score2(aunt: &Aunt, profile: &Aunt) -> i64 {
+    let mut score = 0;
+    for (k, &v) in &profile.things {
+        if let Some(&x) = aunt.things.get(k) {
+            if k == "cats" || k == "trees" {
+                if x > v { score += 1; }
+            } else if k == "pomeranians" || k == "goldfish" {
+                if x < v { score += 1; }
+            } else if x == v { 
+                score += 1;
+            }
+        }
+    }
+    score
+}
+
+pub fn find_best_match<Matcher>(aunts: &[Aunt], profile: &Aunt, func: Matcher) -> usize
+    where Matcher: Fn(&Aunt, &Aunt) -> i64
+{
+    aunts.iter()
+        .enumerate()
+        .max_by(|(_, x), (_, y)| { func(x, profile).cmp(&func(y, profile)) })
+        .unwrap().0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        let aunts = parse_input("Sue 1: cars: 9, akitas: 3, goldfish: 0\nSue 2: trees: 1").unwrap();
+        assert_eq!(aunts[0].number, 1);
+        assert_eq!(aunts[0].things, HashMap::from([("cars".into(), 9), ("akitas".into(), 3), ("goldfish".into(), 0)]));
+        assert_eq!(aunts[1].things.len(), 1);
+        let e = parse_input("Sue 1: cars: 9, akitas 3").unwrap_err();
+        assert_eq!(e.column, 17);
+        let e = parse_input("Sue 1: cars: x").unwrap_err();
+        assert_eq!(e.column, 14);
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = Day16::generate(&mut Rng::new(seed), 50);
+            assert!(Day16::parse(&input).is_ok(), "{}", input);
+        }
+    }
+}
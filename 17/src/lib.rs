@@ -0,0 +1,163 @@
+use aoc_common::{parse_lines, parse_value, Configured, Generator, Param, Params, ParseError, Rng, Solution};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    const NAME: &'static str = "no_such_thing_as_too_much";
+
+    const PARAMS: &'static [Param] = &[
+        Param::new("litres", 150, "Amount of eggnog to store").range(0, i32::MAX as i64),
+    ];
+
+    type Input = Configured<Vec<i32>>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, ParseError> {
+        Ok(Configured { value: parse_input(input)?, params: params.clone() })
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        count_combinations(&input.value, input.params.get_as("litres"))
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        count_minimum_combinations(&input.value, input.params.get_as("litres"))
+    }
+}
+
+impl Generator for Day17 {
+    const DEFAULT_SIZE: usize = 20;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size).map(|_| format!("{}\n", rng.range(1..=50))).collect()
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<i32>, ParseError> {
+    let mut c = parse_lines(input.trim(), |l| {
+        let v = parse_value::<i32>(l, l)?;
+        if v <= 0 {
+            return Err(ParseError::new(l, l, "Container size must be positive"));
+        }
+        Ok(v)
+    })?;
+    c.sort_by(|a, b| b.cmp(a));
+    Ok(c)
+}
+
+fn enumerate_combinations_rec<F>(cs: &mut [i32], csindex: usize, containers: &[i32], index: usize, amount: i32, func: &mut F) where F: FnMut(&[i32]) {
+    if index >= cs.len() { return; }
+    let current_amount = containers[index];
+    enumerate_combinations_rec(cs, csindex, containers, index + 1, amount, func);
+    cs[csindex] = containers[index];
+    if current_amount == amount {
+        func(&cs[0..csindex + 1]);
+    } else if current_amount < amount {
+        enumerate_combinations_rec(cs, csindex + 1, containers, index + 1, amount - current_amount, func);
+    }
+}
+
+pub fn enumerate_combinations<F>(containers: &[i32], amount: i32, mut func: F) where F: FnMut(&[i32]) {
+    let mut cs = vec![0; containers.len()];
+    enumerate_combinations_rec(&mut cs, 0, containers, 0, amount, &mut func);
+}
+
+pub fn count_combinations(containers: &[i32], amount: i32) -> i32 {
+    let mut count = 0;
+    enumerate_combinations(containers, amount, |_| {count += 1;});
+    count
+}
+
+pub fn count_minimum_combinations(containers: &[i32], amount: i32) -> i32 {
+    let mut counts = std::collections::HashMap::<usize, i32>::new();
+    let mut min_key = containers.len();
+    enumerate_combinations(containers, amount, |c| {
+        min_key = std::cmp::min(min_key, c.len());
+        *counts.entry(c.len()).or_insert(0) += 1;
+    });
+    counts.get(&min_key).copied().unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_input() -> &'static str {
+        "20\n15\n10\n5\n5\n"
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let containers = parse_input(sample_input()).unwrap();
+        assert_eq!(containers, vec![20, 15, 10, 5, 5]);
+    }
+
+    #[test]
+    fn test_parse_input_errors() {
+        let e = parse_input("20\n1x\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+        assert!(parse_input("20\n0\n").is_err());
+    }
+
+    #[test]
+    fn test_enumerate_combinations() {
+        let mut count = 0;
+        enumerate_combinations(&parse_input(sample_input()).unwrap(), 25, |c| {
+            match count {
+                0 => { assert_eq!(c, vec![15, 5, 5]); },
+                1 => { assert_eq!(c, vec![15, 10]); },
+                2 => { assert_eq!(c, vec![20, 5]); },
+                3 => { assert_eq!(c, vec![20, 5]); },
+                _ => panic!("Unexpected combination #{} - {:?}", count, c)
+            }
+            count += 1;
+        });
+        assert_eq!(count, 4);
+    }
+
+    #[test]
+    fn test_count_combinations() {
+        assert_eq!(count_combinations(&parse_input(sample_input()).unwrap(), 25), 4);
+    }
+
+    #[test]
+    fn test_count_minimum_combinations() {
+        assert_eq!(count_minimum_combinations(&parse_input(sample_input()).unwrap(), 25), 3);
+    }
+
+    // Every non-empty subset of the containers, as a count of all that fit and of those with the fewest containers
+    fn naive_counts(containers: &[i32], amount: i32) -> (i32, i32) {
+        let fitting: Vec<u32> = (1_u32..1 << containers.len())
+            .filter(|set| (0..containers.len()).filter(|i| set & (1 << i) != 0).map(|i| containers[i]).sum::<i32>() == amount)
+            .map(|set| set.count_ones())
+            .collect();
+        let fewest = fitting.iter().min().map_or(0, |&n| fitting.iter().filter(|&&m| m == n).count());
+        (fitting.len() as i32, fewest as i32)
+    }
+
+    #[test]
+    fn test_combinations_reference() {
+        let generate = |rng: &mut Rng| {
+            let size = rng.range(1..=12) as usize;
+            let containers = parse_input(&Day17::generate(rng, size)).unwrap();
+            let amount = rng.range(1..=containers.iter().sum::<i32>() as i64 + 10) as i32;
+            (containers, amount)
+        };
+        let fast = |(cs, amount): &(Vec<i32>, i32)| (count_combinations(cs, *amount), count_minimum_combinations(cs, *amount));
+        aoc_common::check_against_reference(300, generate, fast, |(cs, amount)| naive_counts(cs, *amount));
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = Day17::generate(&mut Rng::new(seed), 20);
+            assert!(Day17::parse(&input).is_ok(), "{}", input);
+        }
+    }
+}
@@ -0,0 +1,276 @@
+use aoc_common::visual::{self, Frame};
+use aoc_common::{Configured, Example, Generator, Param, Params, ParseError, Rng, Solution};
+use aoc_grid::Grid;
+
+pub struct Day18;
+
+// From the puzzle description
+const SAMPLE: &str = r".#.#.#
+...##.
+#....#
+..#...
+#.#..#
+####..";
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    const NAME: &'static str = "like_a_gif_for_your_yard";
+
+    const PARAMS: &'static [Param] = &[
+        Param::new("steps", 100, "Number of animation steps").range(0, u32::MAX as i64),
+    ];
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(SAMPLE).with_params(&[("steps", 4)]).part1("4"),
+        Example::new(SAMPLE).with_params(&[("steps", 5)]).part2("17"),
+    ];
+
+    type Input = Configured<Field>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, ParseError> {
+        Ok(Configured { value: Field::from_input(input)?, params: params.clone() })
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        animate(input, 1).light_count()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        animate(input, 2).light_count()
+    }
+
+    fn picture(input: &Self::Input) -> Option<String> {
+        Some(animate(input, 2).to_string())
+    }
+}
+
+// The field after all steps, in part 2 the corners are stuck on
+fn animate(input: &Configured<Field>, part: u8) -> Field {
+    let mut f = input.value.clone();
+    if part == 2 {
+        f.corners_on();
+    }
+    let steps = input.params.get("steps");
+    show_field(&f, part, 0, steps);
+    for step in 1..=steps {
+        f = if part == 2 { f.step2() } else { f.step() };
+        show_field(&f, part, step, steps);
+    }
+    f
+}
+
+impl Generator for Day18 {
+    const DEFAULT_SIZE: usize = 100;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        (0..size).map(|_| rng.string_from("#.", size) + "\n").collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Field {
+    grid: Grid<bool>,
+}
+
+impl Field {
+    pub fn new(width: usize, height: usize) -> Self {
+        Field { grid: Grid::new(width, height, false) }
+    }
+
+    pub fn from_input(input: &str) -> Result<Self, ParseError> {
+        Ok(Field { grid: Grid::parse(input)? })
+    }
+
+    pub fn set_cell(&mut self, x: usize, y: usize, value: bool) {
+        self.grid[(x, y)] = value;
+    }
+
+    pub fn cell(&self, x: usize, y: usize) -> bool {
+        self.grid[(x, y)]
+    }
+
+    pub fn count_neighbors(&self, x: usize, y: usize) -> i32 {
+        self.grid.neighbors(x, y).filter(|&p| self.grid[p]).count() as i32
+    }
+}
+
+impl std::fmt::Display for Field {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
+impl Field {
+    pub fn step(&self) -> Field {
+        let grid = Grid::from_fn(self.grid.width(), self.grid.height(), |ix, iy| {
+            let n = self.count_neighbors(ix, iy);
+            n == 3 || (n == 2 && self.cell(ix, iy))
+        });
+        Field { grid }
+    }
+
+    pub fn light_count(&self) -> i32 {
+        self.grid.iter().filter(|&&c| c).count() as i32
+    }
+
+    pub fn corners_on(&mut self) {
+        let (w, h) = (self.grid.width(), self.grid.height());
+        self.set_cell(0, 0, true);
+        self.set_cell(w - 1, 0, true);
+        self.set_cell(0, h - 1, true);
+        self.set_cell(w - 1, h - 1, true);
+    }
+
+    pub fn step2(&self) -> Field {
+        let mut f = self.step();
+        f.corners_on();
+        f
+    }
+}
+
+fn show_field(f: &Field, part: u8, step: i64, steps: i64) {
+    visual::show(|| Frame {
+        title: format!("Part {}, step {}/{}, {} lights on", part, step, steps, f.light_count()),
+        lines: f.grid.shaded(visual::MAX_WIDTH, visual::MAX_HEIGHT, |&on| if on { 1.0 } else { 0.0 }),
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        let f = Field::from_input(SAMPLE).unwrap();
+        assert_eq!(f.grid.width(), 6);
+        assert_eq!(f.grid.height(), 6);
+        assert_eq!(format!("{}", f), SAMPLE.to_owned() + "\n");
+    }
+
+    #[test]
+    fn test_parse_input_errors() {
+        let e = Field::from_input("#.#\n.#\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+        let e = Field::from_input("#.#\n.o.\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 2));
+        assert!(Field::from_input("").is_err());
+    }
+
+    #[test]
+    fn test_count_neighbors() {
+        let f = Field::from_input(SAMPLE).unwrap();
+        assert_eq!(f.count_neighbors(0, 0), 1);
+        assert_eq!(f.count_neighbors(5, 0), 1);
+        assert_eq!(f.count_neighbors(2, 5), 3);
+    }
+
+    #[test]
+    fn test_step() {
+        let mut f = Field::from_input(SAMPLE).unwrap();
+        f = f.step();
+        assert_eq!(format!("{f}"), concat!("..##..", "\n",
+                                           "..##.#", "\n",
+                                           "...##.", "\n",
+                                           "......", "\n",
+                                           "#.....", "\n",
+                                           "#.##..", "\n"));
+        f = f.step();
+        assert_eq!(format!("{f}"), concat!("..###.", "\n",
+                                           "......", "\n",
+                                           "..###.", "\n",
+                                           "......", "\n",
+                                           ".#....", "\n",
+                                           ".#....", "\n"));
+        f = f.step();
+        assert_eq!(format!("{f}"), concat!("...#..", "\n",
+                                           "......", "\n",
+                                           "...#..", "\n",
+                                           "..##..", "\n",
+                                           "......", "\n",
+                                           "......", "\n"));
+        f = f.step();
+        assert_eq!(format!("{f}"), concat!("......", "\n",
+                                           "......", "\n",
+                                           "..##..", "\n",
+                                           "..##..", "\n",
+                                           "......", "\n",
+                                           "......", "\n"));
+        assert_eq!(f.light_count(), 4);
+    }
+
+    #[test]
+    fn test_step2() {
+        let mut f = Field::from_input(SAMPLE).unwrap();
+        f.corners_on();
+        assert_eq!(format!("{f}"), concat!("##.#.#", "\n",
+                                           "...##.", "\n",
+                                           "#....#", "\n",
+                                           "..#...", "\n",
+                                           "#.#..#", "\n",
+                                           "####.#", "\n"));
+        f = f.step2();
+        assert_eq!(format!("{f}"), concat!("#.##.#", "\n",
+                                           "####.#", "\n",
+                                           "...##.", "\n",
+                                           "......", "\n",
+                                           "#...#.", "\n",
+                                           "#.####", "\n"));
+        f = f.step2();
+        assert_eq!(format!("{f}"), concat!("#..#.#", "\n",
+                                           "#....#", "\n",
+                                           ".#.##.", "\n",
+                                           "...##.", "\n",
+                                           ".#..##", "\n",
+                                           "##.###", "\n"));
+        f = f.step2();
+        assert_eq!(format!("{f}"), concat!("#...##", "\n",
+                                           "####.#", "\n",
+                                           "..##.#", "\n",
+                                           "......", "\n",
+                                           "##....", "\n",
+                                           "####.#", "\n"));
+        f = f.step2();
+        assert_eq!(format!("{f}"), concat!("#.####", "\n",
+                                           "#....#", "\n",
+                                           "...#..", "\n",
+                                           ".##...", "\n",
+                                           "#.....", "\n",
+                                           "#.#..#", "\n"));
+        f = f.step2();
+        assert_eq!(format!("{f}"), concat!("##.###", "\n",
+                                           ".##..#", "\n",
+                                           ".##...", "\n",
+                                           ".##...", "\n",
+                                           "#.#...", "\n",
+                                           "##...#", "\n"));
+        assert_eq!(f.light_count(), 17);
+    }
+
+    #[test]
+    fn test_picture() {
+        let mut params = Params::defaults(Day18::PARAMS);
+        params.set("steps", 5).unwrap();
+        let picture = aoc_common::picture::<Day18>(SAMPLE, &params).unwrap();
+        assert_eq!(picture.as_deref(), Some("##.###\n.##..#\n.##...\n.##...\n#.#...\n##...#\n"));
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc_common::check_examples::<Day18>();
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = Day18::generate(&mut Rng::new(seed), 20);
+            assert!(Day18::parse(&input).is_ok(), "{}", input);
+        }
+    }
+}
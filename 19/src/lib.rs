@@ -0,0 +1,240 @@
+use aoc_common::{trace, Budget, Example, Generator, Outcome, ParseError, Rng, Solution};
+use std::collections::HashSet;
+
+pub struct Day19;
+
+// From the puzzle description
+const SAMPLE: &str = r"H => HO
+H => OH
+O => HH
+
+HOH
+";
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    const NAME: &'static str = "medicine_for_rudolph";
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(SAMPLE).part1("4"),
+        Example::new("H => HO\nH => OH\nO => HH\n\nHOHOHO\n").part1("7"),
+        Example::new("e => H\ne => O\nH => HO\nH => OH\nO => HH\n\nHOH\n").part2("3"),
+        Example::new("e => H\ne => O\nH => HO\nH => OH\nO => HH\n\nHOHOHO\n").part2("6"),
+    ];
+
+    type Input = (Vec<Replacement>, String);
+    type Answer1 = usize;
+    type Answer2 = Outcome<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        let (replacements, start) = input;
+        apply_all_replacements(replacements, start).len()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        let (replacements, start) = input;
+        find_replacement_chain_within(replacements, start, &Budget::current())
+    }
+}
+
+impl Generator for Day19 {
+    // The reverse search in part 2 gets slow quickly on random replacement rules
+    const DEFAULT_SIZE: usize = 20;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // Every replacement turns one element into two, and the molecule is built by applying
+        // `size` random replacements starting from "e", so it can always be reduced back.
+        const ELEMENTS: [&str; 10] = ["Al", "B", "Ca", "F", "H", "Mg", "N", "O", "P", "Ti"];
+        let mut replacements = Vec::new();
+        for _ in 0..2 {
+            replacements.push(("e", format!("{}{}", rng.choose(&ELEMENTS), rng.choose(&ELEMENTS))));
+        }
+        for element in ELEMENTS {
+            for _ in 0..rng.range(1..=3) {
+                replacements.push((element, format!("{}{}", rng.choose(&ELEMENTS), rng.choose(&ELEMENTS))));
+            }
+        }
+        let mut molecule = vec!["e"];
+        for _ in 0..size.max(1) {
+            let position = rng.index(molecule.len());
+            let candidates: Vec<_> = replacements.iter().filter(|(from, _)| *from == molecule[position]).collect();
+            let (_, to) = rng.choose(&candidates);
+            let split = ELEMENTS.iter()
+                .find(|e| to.starts_with(*e) && ELEMENTS.contains(&&to[e.len()..]))
+                .expect("Replacements consist of two elements");
+            molecule.splice(position..position + 1, [*split, &to[split.len()..]]);
+        }
+        let mut r: String = replacements.iter().map(|(from, to)| format!("{} => {}\n", from, to)).collect();
+        r += "\n";
+        r += &molecule.concat();
+        r += "\n";
+        r
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Replacement {
+    pub input: String,
+    pub output: String,
+}
+
+pub fn parse_input(input: &str) -> Result<(Vec<Replacement>, String), ParseError> {
+    let mut replacements = Vec::new();
+    let mut in_replacements = true;
+    let mut start = String::new();
+    for (i, line) in input.trim().lines().enumerate() {
+        if line.is_empty() {
+            in_replacements = false;
+        } else if in_replacements {
+            let Some((from, to)) = line.split_once(" => ") else {
+                return Err(ParseError::new(line, line, "Expected '<from> => <to>'").on_line(i + 1));
+            };
+            if from.is_empty() || to.is_empty() {
+                return Err(ParseError::new(line, line, "Empty replacement").on_line(i + 1));
+            }
+            replacements.push(Replacement{ input: from.into(), output: to.into() });
+        } else if start.is_empty() {
+            start = line.into();
+        } else {
+            return Err(ParseError::new(line, line, "Unexpected line after the molecule").on_line(i + 1));
+        }
+    }
+    if start.is_empty() {
+        return Err(ParseError::new(input, input, "Missing molecule"));
+    }
+    Ok((replacements, start))
+}
+
+pub fn apply_all_replacements(replacements: &[Replacement], start: &str) -> HashSet<String> {
+    let mut r = HashSet::new();
+    for Replacement{input, output} in replacements {
+        for (m_idx, _) in start.match_indices(input) {
+            let mut new = start[0..m_idx].to_owned();
+            new.push_str(output);
+            new.push_str(&start[(m_idx+input.len())..]);
+            r.insert(new);
+        }
+    }
+    r
+}
+
+pub fn backward_replacements(replacements: &[Replacement], target: &str, working_set: &mut HashSet<String>) {
+    for Replacement{input, output} in replacements {
+        for (m_idx, _) in target.match_indices(output) {
+            let mut new = target[0..m_idx].to_owned();
+            new.push_str(input);
+            new.push_str(&target[(m_idx+output.len())..]);
+            working_set.insert(new);
+        }
+    }
+}
+
+pub fn find_replacement_chain(replacements: &[Replacement], target: &str) -> i32 {
+    find_replacement_chain_within(replacements, target, &Budget::new()).done().expect("Unlimited budget")
+}
+
+// A step is a molecule reduced, there is no partial answer
+pub fn find_replacement_chain_within(replacements: &[Replacement], target: &str, budget: &Budget) -> Outcome<i32> {
+    let mut words = Vec::<(String, i32)>::new();
+    words.push((target.into(), 0));
+    let mut reduced = 0;
+    loop {
+        if budget.is_exhausted() {
+            return Outcome::Stopped { partial: None, steps: reduced };
+        }
+        words.sort_by(|(a, _), (b, _)| { b.len().cmp(&a.len()) });
+        let (first_word, word_count) = words.pop().unwrap().clone();
+        if first_word == "e" {
+            return Outcome::Done(word_count);
+        }
+        reduced += 1;
+        let mut new_set = HashSet::new();
+        backward_replacements(replacements, &first_word, &mut new_set);
+        trace!("reduce", steps = word_count, molecule = first_word, candidates = new_set.len());
+        for w in &new_set {
+            words.push((w.clone(), word_count + 1));
+        }
+        if words.is_empty() { return Outcome::Done(-1); }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_input2() -> &'static str {
+        r"e => H
+e => O
+H => HO
+H => OH
+O => HH
+
+e"
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let (replacements, start) = parse_input(SAMPLE).unwrap();
+        assert_eq!(replacements, vec![
+            Replacement { input: "H".into(), output: "HO".into() },
+            Replacement { input: "H".into(), output: "OH".into() },
+            Replacement { input: "O".into(), output: "HH".into() },
+        ]);
+        assert_eq!(start, "HOH");
+    }
+
+    #[test]
+    fn test_parse_input_errors() {
+        let e = parse_input("H => HO\nH -> OH\n\nHOH").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+        let e = parse_input("H => HO\n\nHOH\nOHO").unwrap_err();
+        assert_eq!(e.line, 4);
+        assert!(parse_input("H => HO\n").is_err());
+    }
+
+    #[test]
+    fn test_appply_all_replacements() {
+        let (replacements, start) = parse_input(SAMPLE).unwrap();
+        let r = apply_all_replacements(&replacements, &start);
+        assert_eq!(r.len(), 4);
+        assert!(r.contains("HOOH"));
+        assert!(r.contains("HOHO"));
+        assert!(r.contains("OHOH"));
+        assert!(r.contains("HHHH"));
+        assert_eq!(apply_all_replacements(&replacements, "HOHOHO").len(), 7);
+    }
+
+    #[test]
+    fn test_find_replacement_chain() {
+        let (replacements, _) = parse_input(sample_input2()).unwrap();
+        assert_eq!(find_replacement_chain(&replacements, "e"), 0);
+        assert_eq!(find_replacement_chain(&replacements, "HOH"), 3);
+        assert_eq!(find_replacement_chain(&replacements, "HOHOHO"), 6);
+    }
+
+    #[test]
+    fn test_find_replacement_chain_budget() {
+        let (replacements, _) = parse_input(sample_input2()).unwrap();
+        let cancelled = Budget::new();
+        cancelled.cancel();
+        assert_eq!(find_replacement_chain_within(&replacements, "HOHOHO", &cancelled), Outcome::Stopped { partial: None, steps: 0 });
+        assert_eq!(find_replacement_chain_within(&replacements, "HOHOHO", &Budget::new()), Outcome::Done(6));
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc_common::check_examples::<Day19>();
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = Day19::generate(&mut Rng::new(seed), 10);
+            assert!(Day19::parse(&input).is_ok(), "{}", input);
+        }
+    }
+}
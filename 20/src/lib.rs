@@ -0,0 +1,144 @@
+use aoc_common::{parse_value, Configured, Generator, Param, Params, ParseError, Rng, Solution};
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    const NAME: &'static str = "infinite_elves_and_infinite_houses";
+
+    const PARAMS: &'static [Param] = &[
+        Param::new("presents_part1", 10, "Presents an elf delivers per house number in part 1").range(1, u32::MAX as i64),
+        Param::new("presents_part2", 11, "Presents an elf delivers per house number in part 2").range(1, u32::MAX as i64),
+        Param::new("houses_part2", 50, "Houses an elf visits in part 2").range(1, u32::MAX as i64),
+    ];
+
+    type Input = Configured<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, ParseError> {
+        let input = input.trim();
+        let value = parse_value(input, input)?;
+        if value > MAX_PRESENTS {
+            return Err(ParseError::new(input, input, format!("Expected at most {} presents", MAX_PRESENTS)));
+        }
+        Ok(Configured { value, params: params.clone() })
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        let presents: usize = input.params.get_as("presents_part1");
+        let target = input.value.div_ceil(presents);
+        first_house(eratosthenes, target, target)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        let presents = input.params.get_as("presents_part2");
+        let houses = input.params.get_as("houses_part2");
+        first_house(|n| eratosthenes2(n, presents, houses), input.value, input.value.div_ceil(presents))
+    }
+}
+
+impl Generator for Day20 {
+    const DEFAULT_SIZE: usize = 1000000;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(2) as i64;
+        format!("{}\n", rng.range(size / 2..=size))
+    }
+}
+
+// The sieves need a slot for every house up to the answer, this keeps them below a gigabyte
+const MAX_PRESENTS: usize = 100000000;
+
+pub fn eratosthenes(n: usize) -> Vec<usize> {
+    let mut r = Vec::new();
+    r.resize(n, 1);
+    let limit = n;
+    for i in 2..limit {
+        for j in (i..limit).step_by(i) {
+            r[j] += i;
+        }
+    }
+    r
+}
+
+pub fn eratosthenes2(n: usize, presents: usize, houses: usize) -> Vec<usize> {
+    let mut r = vec![0; n];
+    let limit = n;
+    // Unlike in part 1, even the first elf stops after `houses` houses
+    for i in 1..limit {
+        let mut count = 0;
+        for j in (i..limit).step_by(i) {
+            r[j] += i * presents;
+            count += 1;
+            if count == houses { break; }
+        }
+    }
+    r
+}
+
+// The sieve starts out large enough for the puzzle inputs and grows until some house gets `target` presents.
+// Houses are numbered from 1, so small targets are reached by house 1 and not by the unused slot 0.
+// Every house gets at least its own number of presents from its elf, so `last_house` is surely reached.
+fn first_house(sieve: impl Fn(usize) -> Vec<usize>, target: usize, last_house: usize) -> usize {
+    let mut limit = (last_house.max(1) + 1).min(1000000);
+    loop {
+        if let Some(house) = sieve(limit).into_iter().skip(1).position(|n| n >= target) {
+            return house + 1;
+        }
+        limit *= 2;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Presents for every house below `n` from its divisors, elves deliver to their first `houses` houses
+    fn naive_presents(n: usize, presents: usize, houses: usize) -> Vec<usize> {
+        (0..n).map(|house| (1..=house).filter(|elf| house % elf == 0 && house / elf <= houses).sum::<usize>() * presents).collect()
+    }
+
+    #[test]
+    fn test_eratosthenes_reference() {
+        let generate = |rng: &mut Rng| rng.range(1..=500) as usize;
+        aoc_common::check_against_reference(100, generate, |&n| eratosthenes(n)[1..].to_vec(), |&n| naive_presents(n, 1, n)[1..].to_vec());
+    }
+
+    #[test]
+    fn test_eratosthenes2_reference() {
+        let generate = |rng: &mut Rng| (rng.range(1..=500) as usize, rng.range(1..=20) as usize, rng.range(1..=60) as usize);
+        let fast = |&(n, presents, houses): &(usize, usize, usize)| eratosthenes2(n, presents, houses);
+        aoc_common::check_against_reference(100, generate, fast, |&(n, presents, houses)| naive_presents(n, presents, houses));
+    }
+
+    #[test]
+    fn test_first_house() {
+        let solve = |input: &str, presents: i64| {
+            let mut params = Params::defaults(Day20::PARAMS);
+            params.set("presents_part1", presents).unwrap();
+            params.set("presents_part2", presents).unwrap();
+            let input = Day20::parse_with(input, &params).unwrap();
+            (Day20::part1(&input), Day20::part2(&input))
+        };
+        assert_eq!(solve("130", 10), (8, 8));
+        // House 6 gets 120 presents in part 1, one short of 121 when rounding the target down
+        assert_eq!(solve("121", 10), (8, 8));
+        assert_eq!(solve("0", 10), (1, 1));
+        assert_eq!(solve("100", u32::MAX as i64), (1, 1));
+        assert_eq!(solve("100", 1), (48, 48));
+        assert!(Day20::parse("100000001").is_err());
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = Day20::generate(&mut Rng::new(seed), 1000);
+            assert!(Day20::parse(&input).is_ok(), "{}", input);
+        }
+    }
+}
@@ -0,0 +1,343 @@
+use aoc_common::{parse_value, Configured, Example, Generator, Param, Params, ParseError, Rng, Solution};
+
+pub struct Day21;
+
+// From the puzzle description
+const SAMPLE: &str = r"Hit Points: 12
+Damage: 7
+Armor: 2
+";
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    const NAME: &'static str = "rpg_simulator_20xx";
+
+    const PARAMS: &'static [Param] = &[
+        Param::new("hit_points", 100, "Hit points of the player").range(1, i32::MAX as i64),
+    ];
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(SAMPLE).with_params(&[("hit_points", 8)]).part1("65").part2("188"),
+    ];
+
+    type Input = Configured<Character>;
+    // `None` when every loadout wins or every loadout loses
+    type Answer1 = Option<i32>;
+    type Answer2 = Option<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, ParseError> {
+        Ok(Configured { value: parse_input(input)?, params: params.clone() })
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        cheapest_win(&input.value, input.params.get_as("hit_points"))
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        most_expensive_loss(&input.value, input.params.get_as("hit_points"))
+    }
+}
+
+impl Generator for Day21 {
+    const DEFAULT_SIZE: usize = 100;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(2) as i64;
+        format!("Hit Points: {}\nDamage: {}\nArmor: {}\n", rng.range(size / 2..=size), rng.range(4..=9), rng.range(0..=4))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Character {
+    pub hit_points: i32,
+    pub damage: i32,
+    pub armor: i32,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Weapon {
+    pub name: String,
+    pub cost: i32,
+    pub damage: i32,
+}
+
+pub fn weapons() -> Vec<Weapon> {
+    vec![
+        Weapon { name: "Dagger".into(), cost: 8,  damage: 4 },
+        Weapon { name: "Shortsword".into(), cost: 10,  damage: 5 },
+        Weapon { name: "Warhammer".into(), cost: 25,  damage: 6 },
+        Weapon { name: "Longsword".into(), cost: 40,  damage: 7 },
+        Weapon { name: "Greataxe".into(), cost: 74,  damage: 8 },
+    ]
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Armor {
+    pub name: String,
+    pub cost: i32,
+    pub armor: i32,
+}
+
+pub fn armors() -> Vec<Armor> {
+    vec![
+        Armor { name: "Leather".into(), cost: 13, armor: 1 },
+        Armor { name: "Chainmail".into(), cost: 31, armor: 2 },
+        Armor { name: "Splintmail".into(), cost: 53, armor: 3 },
+        Armor { name: "Bandedmail".into(), cost: 75, armor: 4 },
+        Armor { name: "Platemail".into(), cost: 102, armor: 5 },
+    ]
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Ring {
+    pub name: String,
+    pub cost: i32,
+    pub damage: i32,
+    pub armor: i32,
+}
+
+pub fn rings() -> Vec<Ring> {
+    vec![
+        Ring { name: "Damage +1".into(), cost: 25, damage: 1, armor: 0 },
+        Ring { name: "Damage +2".into(), cost: 50, damage: 2, armor: 0 },
+        Ring { name: "Damage +3".into(), cost: 100, damage: 3, armor: 0 },
+        Ring { name: "Defense +1".into(), cost: 20, damage: 0, armor: 1 },
+        Ring { name: "Defense +2".into(), cost: 40, damage: 0, armor: 2 },
+        Ring { name: "Defense +3".into(), cost: 80, damage: 0, armor: 3 },
+    ]
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Inventory {
+    pub weapon: Weapon,
+    pub armor: Option<Armor>,
+    pub ring1: Option<Ring>,
+    pub ring2: Option<Ring>,
+}
+
+impl Inventory {
+    pub fn cost(&self) -> i32 {
+        self.weapon.cost +
+        self.armor.as_ref().map_or(0, |a| a.cost) +
+        self.ring1.as_ref().map_or(0, |r| r.cost) +
+        self.ring2.as_ref().map_or(0, |r| r.cost)
+    }
+
+    pub fn damage(&self) -> i32 {
+        self.weapon.damage +
+        self.ring1.as_ref().map_or(0, |r| r.damage) +
+        self.ring2.as_ref().map_or(0, |r| r.damage)
+    }
+
+    pub fn armor(&self) -> i32 {
+        self.armor.as_ref().map_or(0, |a| a.armor) +
+        self.ring1.as_ref().map_or(0, |r| r.armor) +
+        self.ring2.as_ref().map_or(0, |r| r.armor)
+    }
+}
+
+pub fn all_inventories() -> Vec<Inventory> {
+    use itertools::Itertools;
+    let mut r = Vec::new();
+    let mut armors: Vec<_> = armors().into_iter().map(Some).collect();
+    armors.push(None);
+    let mut rings: Vec<_> = rings().into_iter().map(Some).collect();
+    rings.push(None);
+    let rings: Vec<_> = rings.clone().into_iter().cartesian_product(rings)
+        .filter(|(r1, r2)| {
+            !(r1.is_some() && r2.is_some() && r1.as_ref().unwrap() == r2.as_ref().unwrap())
+        })
+        .collect();
+    for w in weapons() {
+        for a in &armors {
+            for (r1, r2) in &rings {
+                let inv = Inventory{ weapon: w.clone(), armor: a.clone(), ring1: r1.clone(), ring2: r2.clone() };
+                r.push(inv);
+            }
+        }
+    }
+    r
+}
+
+pub fn player(hit_points: i32) -> Character {
+    Character {
+        hit_points,
+        damage: 0,
+        armor: 0,
+    }
+}
+
+pub fn player_equipped_with(inventory: &Inventory, hit_points: i32) -> Character {
+    let mut p = player(hit_points);
+    p.damage += inventory.damage();
+    p.armor += inventory.armor();
+    p
+}
+
+pub fn parse_input(input: &str) -> Result<Character, ParseError> {
+    let mut lines = input.lines().enumerate();
+    let hit_points = parse_stat(input, &mut lines, "Hit Points")?;
+    let damage = parse_stat(input, &mut lines, "Damage")?;
+    let armor = parse_stat(input, &mut lines, "Armor")?;
+    Ok(Character { hit_points, damage, armor })
+}
+
+fn parse_stat<'a>(input: &str, lines: &mut impl Iterator<Item = (usize, &'a str)>, key: &str) -> Result<i32, ParseError> {
+    let Some((i, line)) = lines.next() else {
+        return Err(ParseError::at_offset(input, input.len(), format!("Missing '{}'", key)));
+    };
+    match line.split_once(':') {
+        Some((k, v)) if k == key => parse_value(line, v.trim()).map_err(|e| e.on_line(i + 1)),
+        _ => Err(ParseError::new(line, line, format!("Expected '{}: <value>'", key)).on_line(i + 1)),
+    }
+}
+
+pub fn damage_round(attacker: &Character, defender: &Character) -> i32 {
+    
+    std::cmp::max(attacker.damage - defender.armor, 1)
+}
+
+pub fn do_battle(boss: &Character, player: &Character) -> bool {
+    let mut boss_hp = boss.hit_points;
+    let mut player_hp = player.hit_points;
+    loop {
+        // Player attacks first
+        boss_hp -= damage_round(player, boss);
+        if boss_hp <= 0 { return true; }
+        player_hp -= damage_round(boss, player);
+        if player_hp <= 0 { return false; }
+    }
+}
+
+pub fn cheapest_win(boss: &Character, hit_points: i32) -> Option<i32> {
+    all_inventories().iter()
+        .filter(|inventory| {
+            let player = player_equipped_with(inventory, hit_points);
+            do_battle(boss, &player)
+        })
+        .map(|inventory| inventory.cost())
+        .min()
+}
+
+pub fn most_expensive_loss(boss: &Character, hit_points: i32) -> Option<i32> {
+    all_inventories().iter()
+        .filter(|inventory| {
+            let player = player_equipped_with(inventory, hit_points);
+            !do_battle(boss, &player)
+        })
+        .map(|inventory| inventory.cost())
+        .max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        let c = parse_input(SAMPLE).unwrap();
+        assert_eq!(c.hit_points, 12);
+        assert_eq!(c.damage, 7);
+        assert_eq!(c.armor, 2);
+    }
+
+    #[test]
+    fn test_parse_input_errors() {
+        let e = parse_input("Hit Points: 12\nDamage: x\nArmor: 2\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 9));
+        let e = parse_input("Hit Points: 12\nArmor: 2\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+        let e = parse_input("Hit Points: 12\nDamage: 7\n").unwrap_err();
+        assert_eq!(e.line, 3);
+    }
+
+    #[test]
+    fn test_damage_round() {
+        assert_eq!(damage_round(&Character { hit_points: 100, damage: 8, armor: 0 },
+                                &Character { hit_points: 100, damage: 0, armor: 3 }), 5);
+        assert_eq!(damage_round(&Character { hit_points: 100, damage: 8, armor: 0 },
+                                &Character { hit_points: 100, damage: 0, armor: 300 }), 1);
+    }
+
+    #[test]
+    fn test_do_battle() {
+        assert!(do_battle(
+            &Character { hit_points: 12, damage: 7, armor: 2 },
+            &Character { hit_points: 8, damage: 5, armor: 5 }));
+    }
+
+    #[test]
+    fn test_cost() {
+        assert_eq!(Inventory{ weapon: Weapon{ cost: 10, name: "".into(), damage: 1 }, armor: None, ring1: None, ring2: None }.cost(), 10);
+        assert_eq!(Inventory{ weapon: Weapon{ cost: 10, name: "".into(), damage: 1 },
+                              armor: Some(Armor { cost: 7, name: "".into(), armor: 1 }), ring1: None, ring2: None }.cost(), 17);
+        assert_eq!(Inventory{ weapon: Weapon{ cost: 10, name: "".into(), damage: 1 },
+                              armor: Some(Armor { cost: 7, name: "".into(), armor: 1 }),
+                              ring1: Some(Ring{ name: "".into(), cost: 8, damage: 1, armor: 1 }), ring2: None }.cost(), 25);
+        assert_eq!(Inventory{ weapon: Weapon{ cost: 10, name: "".into(), damage: 1 },
+                              armor: Some(Armor { cost: 7, name: "".into(), armor: 1 }),
+                              ring1: Some(Ring{ name: "".into(), cost: 8, damage: 1, armor: 1 }),
+                              ring2: Some(Ring{ name: "".into(), cost: 9, damage: 1, armor: 1 })
+                            }.cost(), 34);
+    }
+
+    #[test]
+    fn test_damage() {
+        assert_eq!(Inventory{ weapon: Weapon{ cost: 10, name: "".into(), damage: 1 }, armor: None, ring1: None, ring2: None }.damage(), 1);
+        assert_eq!(Inventory{ weapon: Weapon{ cost: 10, name: "".into(), damage: 1 },
+                              armor: Some(Armor { cost: 7, name: "".into(), armor: 1 }), ring1: None, ring2: None }.damage(), 1);
+        assert_eq!(Inventory{ weapon: Weapon{ cost: 10, name: "".into(), damage: 1 },
+                              armor: Some(Armor { cost: 7, name: "".into(), armor: 1 }),
+                              ring1: Some(Ring{ name: "".into(), cost: 8, damage: 9, armor: 1 }), ring2: None }.damage(), 10);
+        assert_eq!(Inventory{ weapon: Weapon{ cost: 10, name: "".into(), damage: 1 },
+                              armor: Some(Armor { cost: 7, name: "".into(), armor: 1 }),
+                              ring1: Some(Ring{ name: "".into(), cost: 8, damage: 9, armor: 1 }),
+                              ring2: Some(Ring{ name: "".into(), cost: 9, damage: 7, armor: 1 })
+                            }.damage(), 17);
+    }
+
+    #[test]
+    fn test_armor() {
+        assert_eq!(Inventory{ weapon: Weapon{ cost: 10, name: "".into(), damage: 1 }, armor: None, ring1: None, ring2: None }.armor(), 0);
+        assert_eq!(Inventory{ weapon: Weapon{ cost: 10, name: "".into(), damage: 1 },
+                              armor: Some(Armor { cost: 7, name: "".into(), armor: 1 }), ring1: None, ring2: None }.armor(), 1);
+        assert_eq!(Inventory{ weapon: Weapon{ cost: 10, name: "".into(), damage: 1 },
+                              armor: Some(Armor { cost: 7, name: "".into(), armor: 1 }),
+                              ring1: Some(Ring{ name: "".into(), cost: 8, damage: 1, armor: 9 }), ring2: None }.armor(), 10);
+        assert_eq!(Inventory{ weapon: Weapon{ cost: 10, name: "".into(), damage: 1 },
+                              armor: Some(Armor { cost: 7, name: "".into(), armor: 1 }),
+                              ring1: Some(Ring{ name: "".into(), cost: 8, damage: 1, armor: 9 }),
+                              ring2: Some(Ring{ name: "".into(), cost: 9, damage: 1, armor: 5 })
+                            }.armor(), 15);
+    }
+
+    #[test]
+    fn test_no_loadout() {
+        let boss = parse_input(SAMPLE).unwrap();
+        assert_eq!(cheapest_win(&boss, 1), None);
+        assert_eq!(most_expensive_loss(&boss, 1), Some(356));
+        assert_eq!(cheapest_win(&boss, 1000), Some(8));
+        assert_eq!(most_expensive_loss(&boss, 1000), None);
+        let mut params = Params::defaults(Day21::PARAMS);
+        params.set("hit_points", 1).unwrap();
+        let input = Day21::parse_with(SAMPLE, &params).unwrap();
+        assert_eq!(aoc_common::Answer::to_answer(&Day21::part1(&input)), Some("none".into()));
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc_common::check_examples::<Day21>();
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = Day21::generate(&mut Rng::new(seed), 100);
+            assert!(Day21::parse(&input).is_ok(), "{}", input);
+        }
+    }
+}
@@ -0,0 +1,460 @@
+use aoc_common::{parse_value, trace, Budget, Configured, Generator, Outcome, Param, Params, ParseError, Rng, Solution};
+use std::collections::HashMap;
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    const NAME: &'static str = "wizard_simulator_20xx";
+
+    const PARAMS: &'static [Param] = &[
+        Param::new("hit_points", 50, "Hit points of the player").range(1, i32::MAX as i64),
+        Param::new("mana", 500, "Mana the player starts with").range(0, i32::MAX as i64),
+    ];
+
+    type Input = Configured<BossCharacter>;
+    // `None` when no game can be won
+    type Answer1 = Outcome<Option<i32>>;
+    type Answer2 = Outcome<Option<i32>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, ParseError> {
+        Ok(Configured { value: parse_input(input)?, params: params.clone() })
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        find_cheapest_game_within(&player(&input.params), &input.value, false, &Budget::current())
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        find_cheapest_game_within(&player(&input.params), &input.value, true, &Budget::current())
+    }
+}
+
+impl Generator for Day22 {
+    const DEFAULT_SIZE: usize = 60;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(2) as i64;
+        format!("Hit Points: {}\nDamage: {}\n", rng.range(size / 2..=size), rng.range(5..=10))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct BossCharacter {
+    pub hit_points: i32,
+    pub damage: i32,
+}
+
+pub fn parse_input(input: &str) -> Result<BossCharacter, ParseError> {
+    let mut lines = input.lines().enumerate();
+    let hit_points = parse_stat(input, &mut lines, "Hit Points")?;
+    let damage = parse_stat(input, &mut lines, "Damage")?;
+    Ok(BossCharacter { hit_points, damage })
+}
+
+fn parse_stat<'a>(input: &str, lines: &mut impl Iterator<Item = (usize, &'a str)>, key: &str) -> Result<i32, ParseError> {
+    let Some((i, line)) = lines.next() else {
+        return Err(ParseError::at_offset(input, input.len(), format!("Missing '{}'", key)));
+    };
+    match line.split_once(':') {
+        Some((k, v)) if k == key => parse_value(line, v.trim()).map_err(|e| e.on_line(i + 1)),
+        _ => Err(ParseError::new(line, line, format!("Expected '{}: <value>'", key)).on_line(i + 1)),
+    }
+}
+
+#[derive(Clone)]
+pub struct Spell {
+    pub name: String,
+    pub mana_cost: i32,
+    pub damage: i32,
+    pub heal: i32,
+    pub effect: Effect,
+}
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub enum Effect {
+    None,
+    Shield,
+    Poison,
+    Recharge,
+}
+
+impl Effect {
+    pub fn duration(&self) -> i32 {
+        match self {
+            Effect::None => 0,
+            Effect::Shield => 6,
+            Effect::Poison => 6,
+            Effect::Recharge => 5,
+        }
+    }
+}
+
+/*
+    Magic Missile costs 53 mana. It instantly does 4 damage.
+    Drain costs 73 mana. It instantly does 2 damage and heals you for 2 hit points.
+    Shield costs 113 mana. It starts an effect that lasts for 6 turns. While it is active, your armor is increased by 7.
+    Poison costs 173 mana. It starts an effect that lasts for 6 turns. 
+    At the start of each turn while it is active, it deals the boss 3 damage.
+    Recharge costs 229 mana. It starts an effect that lasts for 5 turns.
+    At the start of each turn while it is active, it gives you 101 new mana.
+*/
+pub fn all_spells() -> Vec<Spell> {
+    vec![
+        Spell { name: "Magic Missile".into(), mana_cost: 53, damage: 4, heal: 0, effect: Effect::None },
+        Spell { name: "Drain".into(), mana_cost: 73, damage: 2, heal: 2, effect: Effect::None },
+        Spell { name: "Shield".into(), mana_cost: 113, damage: 0, heal: 0, effect: Effect::Shield },
+        Spell { name: "Poison".into(), mana_cost: 173, damage: 0, heal: 0, effect: Effect::Poison },
+        Spell { name: "Recharge".into(), mana_cost: 229, damage: 0, heal: 0, effect: Effect::Recharge },
+    ]
+}
+
+#[derive(Clone)]
+pub struct ActiveEffects {
+    active: HashMap<Effect, i32>,
+}
+
+impl Default for ActiveEffects {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ActiveEffects {
+    pub fn new() -> Self {
+        Self { active: HashMap::new() }
+    }
+
+    pub fn is_active(&self, effect: Effect) -> bool {
+        self.active.contains_key(&effect)
+    }
+
+    pub fn is_valid_to_cast(&self, effect: Effect) -> bool {
+        let a = self.active.get(&effect);
+        match a {
+            None => true,
+            Some(&n) => n == 1,
+        }
+    }
+
+    pub fn next_turn(&mut self) {
+        self.active.retain(|_k, v| {
+            *v -= 1;
+            *v != 0
+         });
+    }
+
+    pub fn add_effect(&mut self, effect: Effect) {
+        if effect == Effect::None { return; }
+        let duration = effect.duration();
+        let r = self.active.insert(effect, duration);
+        assert!(r.is_none());
+    }
+}
+
+#[derive(Clone)]
+pub struct PlayerCharacter {
+    pub hit_points: i32,
+    pub mana: i32,
+    pub active_effects: ActiveEffects,
+}
+
+impl Default for PlayerCharacter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PlayerCharacter {
+    pub fn new() -> Self {
+        Self { hit_points: 50, mana: 500, active_effects: ActiveEffects::new() }
+    }
+}
+
+fn player(params: &Params) -> PlayerCharacter {
+    PlayerCharacter { hit_points: params.get_as("hit_points"), mana: params.get_as("mana"), active_effects: ActiveEffects::new() }
+}
+
+pub fn play_turn(player: &PlayerCharacter, boss: &BossCharacter, action: &Spell, hard_mode: bool) -> (PlayerCharacter, BossCharacter) {
+    let mut ret_player = (*player).clone();
+    let mut ret_boss = *boss;
+    #[derive(PartialEq, Eq)] enum Turn { Player, Boss }
+    for turn in [Turn::Player, Turn::Boss] {
+        if hard_mode && turn == Turn::Player {
+            ret_player.hit_points -= 1;
+            if ret_player.hit_points <= 0 {
+                break;
+            }
+        }
+        if ret_player.active_effects.is_active(Effect::Poison) { ret_boss.hit_points -= 3; }
+        if ret_player.active_effects.is_active(Effect::Recharge) { ret_player.mana += 101; }
+        ret_player.active_effects.next_turn();
+        match turn {
+            Turn::Player => {
+                ret_player.active_effects.add_effect(action.effect);
+                ret_boss.hit_points -= action.damage;
+                ret_player.hit_points += action.heal;
+                ret_player.mana -= action.mana_cost;
+                trace!("cast", spell = action.name, player_hit_points = ret_player.hit_points,
+                       player_mana = ret_player.mana, boss_hit_points = ret_boss.hit_points);
+            },
+            Turn::Boss => {
+                if ret_boss.hit_points > 0 {
+                    let is_shielded = ret_player.active_effects.is_active(Effect::Shield);
+                    let boss_damage = ret_boss.damage - if is_shielded { 7 } else { 0 };
+                    ret_player.hit_points -= std::cmp::max(1, boss_damage);
+                }
+            },
+        }
+    }
+    (ret_player, ret_boss)
+}
+
+pub fn is_valid_action(player: &PlayerCharacter, action: &Spell) -> bool {
+    action.mana_cost <= player.mana && player.active_effects.is_valid_to_cast(action.effect)
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum GameResult {
+    Pending,
+    PlayerWins,
+    BossWins,
+    Invalid,
+}
+
+pub fn play_game(player: &PlayerCharacter, boss: &BossCharacter, turns: &[usize], hard_mode: bool) -> GameResult {
+    let spells = all_spells();
+    let mut player = (*player).clone();
+    let mut boss = *boss;
+    for &turn in turns {
+        let action = &spells[turn];
+        if !is_valid_action(&player, action) { return GameResult::Invalid; }
+        (player, boss) = play_turn(&player, &boss, action, hard_mode);
+        if player.hit_points <= 0 { return GameResult::BossWins; }
+        if boss.hit_points <= 0 { return GameResult::PlayerWins; }
+    }
+    GameResult::Pending
+}
+
+pub fn mana_cost_for_game(turns: &[usize]) -> i32 {
+    let spells = all_spells();
+    turns.iter().fold(0, |acc, turn| {
+        let action = &spells[*turn];
+        acc + action.mana_cost
+    })
+}
+
+pub fn find_cheapest_game(player: &PlayerCharacter, boss: &BossCharacter, hard_mode: bool) -> Option<i32> {
+    find_cheapest_game_within(player, boss, hard_mode, &Budget::new()).done().expect("Unlimited budget")
+}
+
+// A step is a game played, the partial answer is the cheapest win found so far
+pub fn find_cheapest_game_within(player: &PlayerCharacter, boss: &BossCharacter, hard_mode: bool, budget: &Budget) -> Outcome<Option<i32>> {
+    let mut turns = Vec::new();
+    let mut games = Games { budget, played: 0, stopped: false };
+    // The search starts from `i32::MAX`, which it keeps when no game is won
+    let min = find_cheapest_game_rec(player, boss, &mut turns, i32::MAX, hard_mode, &mut games);
+    let min = (min != i32::MAX).then_some(min);
+    if games.stopped {
+        return Outcome::Stopped { partial: min.map(Some), steps: games.played };
+    }
+    Outcome::Done(min)
+}
+
+struct Games<'a> {
+    budget: &'a Budget,
+    played: u64,
+    stopped: bool,
+}
+
+fn find_cheapest_game_rec(player: &PlayerCharacter, boss: &BossCharacter, turns: &mut Vec<usize>, mut current_min: i32, hard_mode: bool,
+                          games: &mut Games) -> i32 {
+    // DFS: prune as soon as we use more mana than current minimum
+    let n_spells = 5;
+    let cheapest_spell = 53;
+    turns.push(0);
+    while *turns.last().unwrap() < n_spells && !games.stopped {
+        games.played += 1;
+        if games.played.is_multiple_of(0x1000) && games.budget.is_exhausted() {
+            games.stopped = true;
+            break;
+        }
+        match play_game(player, boss, turns, hard_mode) {
+            GameResult::PlayerWins => {
+                current_min = std::cmp::min(current_min, mana_cost_for_game(turns));
+            },
+            GameResult::Pending
+                if mana_cost_for_game(turns) + cheapest_spell < current_min => {
+                    current_min = find_cheapest_game_rec(player, boss, turns, current_min, hard_mode, games);
+                },
+            _ => {},
+        }
+        *turns.last_mut().unwrap() += 1;
+    }
+    turns.pop();
+    current_min
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_game_1() {
+        let player = PlayerCharacter { hit_points: 10, mana: 250, active_effects: ActiveEffects::new() };
+        let boss = BossCharacter { hit_points: 13, damage: 8 };
+        let spells = all_spells();
+        assert!(!player.active_effects.is_active(Effect::Poison));
+        assert_eq!(player.hit_points, 10);
+        assert_eq!(player.mana, 250);
+        assert_eq!(boss, BossCharacter{ hit_points: 13, damage: 8 });
+        
+        let (player, boss) = play_turn(&player, &boss, &spells[3], false);
+        assert!(player.active_effects.is_active(Effect::Poison));
+        assert_eq!(player.hit_points, 2);
+        assert_eq!(player.mana, 77);
+        assert_eq!(boss, BossCharacter{ hit_points: 10, damage: 8 });
+
+        let (player, boss) = play_turn(&player, &boss, &spells[0], false);
+        assert!(player.active_effects.is_active(Effect::Poison));
+        assert_eq!(player.hit_points, 2);
+        assert_eq!(player.mana, 24);
+        assert_eq!(boss, BossCharacter{ hit_points: 0, damage: 8 });
+    }
+
+    #[test]
+    fn test_game_2() {
+        let player = PlayerCharacter { hit_points: 10, mana: 250, active_effects: ActiveEffects::new() };
+        let boss = BossCharacter { hit_points: 14, damage: 8 };
+        let spells = all_spells();
+        assert!(!player.active_effects.is_active(Effect::Recharge));
+        assert_eq!(player.hit_points, 10);
+        assert_eq!(player.mana, 250);
+        assert_eq!(boss, BossCharacter{ hit_points: 14, damage: 8 });
+
+        // cast recharge
+        let (player, boss) = play_turn(&player, &boss, &spells[4], false);
+        assert!(player.active_effects.is_active(Effect::Recharge));
+        assert_eq!(player.hit_points, 2);
+        assert_eq!(player.mana, 122);
+        assert_eq!(boss, BossCharacter{ hit_points: 14, damage: 8 });
+
+        // cast shield
+        let (player, boss) = play_turn(&player, &boss, &spells[2], false);
+        assert!(player.active_effects.is_active(Effect::Recharge));
+        assert!(player.active_effects.is_active(Effect::Shield));
+        assert_eq!(player.hit_points, 1);
+        assert_eq!(player.mana, 211);
+        assert_eq!(boss, BossCharacter{ hit_points: 14, damage: 8 });
+
+        // cast drain
+        let (player, boss) = play_turn(&player, &boss, &spells[1], false);
+        assert!(player.active_effects.is_active(Effect::Shield));
+        assert_eq!(player.hit_points, 2);
+        assert_eq!(player.mana, 340);
+        assert_eq!(boss, BossCharacter{ hit_points: 12, damage: 8 });
+
+        // cast poison
+        let (player, boss) = play_turn(&player, &boss, &spells[3], false);
+        assert!(player.active_effects.is_active(Effect::Shield));
+        assert!(player.active_effects.is_active(Effect::Poison));
+        assert_eq!(player.hit_points, 1);
+        assert_eq!(player.mana, 167);
+        assert_eq!(boss, BossCharacter{ hit_points: 9, damage: 8 });
+
+        // cast missile
+        let (player, boss) = play_turn(&player, &boss, &spells[0], false);
+        assert!(player.active_effects.is_active(Effect::Poison));
+        assert_eq!(player.hit_points, 1);
+        assert_eq!(player.mana, 114);
+        assert_eq!(boss, BossCharacter{ hit_points: -1, damage: 8 });
+    }
+
+    #[test]
+    fn test_play_game() {
+        assert_eq!(play_game(&PlayerCharacter { hit_points: 10, mana: 250, active_effects: ActiveEffects::new() },
+                             &BossCharacter { hit_points: 13, damage: 8 },
+                             &[3], false), GameResult::Pending);
+        assert_eq!(play_game(&PlayerCharacter { hit_points: 10, mana: 250, active_effects: ActiveEffects::new() },
+                             &BossCharacter { hit_points: 13, damage: 8 },
+                             &[3, 0], false), GameResult::PlayerWins);
+        assert_eq!(play_game(&PlayerCharacter { hit_points: 10, mana: 250, active_effects: ActiveEffects::new() },
+                             &BossCharacter { hit_points: 13, damage: 8 },
+                             &[3, 3], false), GameResult::Invalid);
+        assert_eq!(play_game(&PlayerCharacter { hit_points: 10, mana: 250, active_effects: ActiveEffects::new() },
+                             &BossCharacter { hit_points: 13, damage: 8 },
+                             &[3, 1], false), GameResult::BossWins);
+
+        assert_eq!(play_game(&PlayerCharacter { hit_points: 10, mana: 250, active_effects: ActiveEffects::new() },
+                             &BossCharacter { hit_points: 14, damage: 8 },
+                             &[3, 0], false), GameResult::BossWins);
+        assert_eq!(play_game(&PlayerCharacter { hit_points: 10, mana: 250, active_effects: ActiveEffects::new() },
+                             &BossCharacter { hit_points: 14, damage: 8 },
+                             &[4, 2, 1, 3], false), GameResult::Pending);
+        assert_eq!(play_game(&PlayerCharacter { hit_points: 10, mana: 250, active_effects: ActiveEffects::new() },
+                             &BossCharacter { hit_points: 14, damage: 8 },
+                             &[4, 2, 1, 3, 0], false), GameResult::PlayerWins);
+        assert_eq!(play_game(&PlayerCharacter { hit_points: 10, mana: 250, active_effects: ActiveEffects::new() },
+                             &BossCharacter { hit_points: 14, damage: 8 },
+                             &[4, 2, 1, 3, 3], false), GameResult::Invalid);
+        assert_eq!(play_game(&PlayerCharacter { hit_points: 10, mana: 250, active_effects: ActiveEffects::new() },
+                             &BossCharacter { hit_points: 14, damage: 8 },
+                             &[4, 2, 1, 3, 1], false), GameResult::BossWins);
+        assert_eq!(play_game(&PlayerCharacter { hit_points: 10, mana: 250, active_effects: ActiveEffects::new() },
+                             &BossCharacter { hit_points: 14, damage: 8 },
+                             &[2, 2, 2, 2], false), GameResult::Invalid);
+    }
+
+    #[test]
+    fn test_mana_cost() {
+        assert_eq!(mana_cost_for_game(&[3, 0]), 226);
+        
+        assert_eq!(mana_cost_for_game(&[4, 2, 1, 3, 0]), 641);
+    }
+
+    #[test]
+    fn test_find_cheapest_game() {
+        assert_eq!(find_cheapest_game(&PlayerCharacter { hit_points: 10, mana: 250, active_effects: ActiveEffects::new() },
+                                      &BossCharacter { hit_points: 13, damage: 8 }, false), Some(226));
+        assert_eq!(find_cheapest_game(&PlayerCharacter { hit_points: 10, mana: 250, active_effects: ActiveEffects::new() },
+                                      &BossCharacter { hit_points: 14, damage: 8 }, false), Some(641));
+
+        assert_eq!(find_cheapest_game(&PlayerCharacter { hit_points: 10, mana: 250, active_effects: ActiveEffects::new() },
+                                      &BossCharacter { hit_points: 13, damage: 8 }, true), None);
+        assert_eq!(find_cheapest_game(&PlayerCharacter { hit_points: 10, mana: 250, active_effects: ActiveEffects::new() },
+                                      &BossCharacter { hit_points: 8, damage: 8 }, true), Some(219));
+    }
+
+    #[test]
+    fn test_find_cheapest_game_budget() {
+        let cancelled = Budget::new();
+        cancelled.cancel();
+        let boss = BossCharacter { hit_points: 71, damage: 10 };
+        assert!(matches!(find_cheapest_game_within(&PlayerCharacter::new(), &boss, false, &cancelled), Outcome::Stopped { steps: 0x1000, .. }));
+        let player = PlayerCharacter { hit_points: 10, mana: 250, active_effects: ActiveEffects::new() };
+        assert_eq!(find_cheapest_game_within(&player, &BossCharacter { hit_points: 13, damage: 8 }, false, &Budget::new()), Outcome::Done(Some(226)));
+    }
+
+    #[test]
+    fn test_no_winning_game() {
+        for (name, value) in [("mana", 0), ("hit_points", 1)] {
+            let mut params = Params::defaults(Day22::PARAMS);
+            params.set(name, value).unwrap();
+            let input = Day22::parse_with("Hit Points: 58\nDamage: 9\n", &params).unwrap();
+            assert_eq!(Day22::part1(&input), Outcome::Done(None), "{}", name);
+            assert_eq!(aoc_common::Answer::to_answer(&Day22::part2(&input)), Some("none".into()), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = Day22::generate(&mut Rng::new(seed), 20);
+            assert!(Day22::parse(&input).is_ok(), "{}", input);
+        }
+    }
+}
@@ -0,0 +1,323 @@
+use aoc_common::{parse_lines, parse_value, trace, Budget, Generator, Outcome, ParseError, Rng, Solution};
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    const NAME: &'static str = "opening_the_turing_lock";
+
+    type Input = Vec<Instruction>;
+    type Answer1 = Outcome<usize>;
+    type Answer2 = Outcome<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        execute_program_within(input, &Budget::current()).map(|(_a, b)| b)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        let mut program = input.clone();
+        program.insert(0, Instruction::Increment('a'));
+        execute_program_within(&program, &Budget::current()).map(|(_a, b)| b)
+    }
+}
+
+impl Generator for Day23 {
+    const DEFAULT_SIZE: usize = 50;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // Only forward jumps, so every program terminates, and a bounded number of triplings
+        // so registers never overflow
+        let mut triples = 0;
+        let mut r = String::new();
+        for i in 0..size {
+            let register = rng.choose(&["a", "b"]);
+            let offset = rng.range(1..=(size - i).min(10) as i64);
+            match rng.index(6) {
+                0 => r += &format!("hlf {}\n", register),
+                1 if triples < 30 => {
+                    triples += 1;
+                    r += &format!("tpl {}\n", register);
+                },
+                2 => r += &format!("jmp +{}\n", offset),
+                3 => r += &format!("jie {}, +{}\n", register, offset),
+                4 => r += &format!("jio {}, +{}\n", register, offset),
+                _ => r += &format!("inc {}\n", register),
+            }
+        }
+        r
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Instruction {
+    Half(char),
+    Triple(char),
+    Increment(char),
+    Jump(i32),
+    JumpIfEven(char, i32),
+    JumpIfOne(char, i32),
+}
+
+impl std::fmt::Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Instruction::Half(r) => write!(f, "hlf {}", r),
+            Instruction::Triple(r) => write!(f, "tpl {}", r),
+            Instruction::Increment(r) => write!(f, "inc {}", r),
+            Instruction::Jump(offset) => write!(f, "jmp {:+}", offset),
+            Instruction::JumpIfEven(r, offset) => write!(f, "jie {}, {:+}", r, offset),
+            Instruction::JumpIfOne(r, offset) => write!(f, "jio {}, {:+}", r, offset),
+        }
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse_lines(input, |l| {
+        let Some((opcode, args)) = l.split_once(' ') else {
+            return Err(ParseError::new(l, l, "Expected '<opcode> <arguments>'"));
+        };
+        let parse_register = |s: &str| -> Result<char, ParseError> {
+            match s {
+                "a" => Ok('a'),
+                "b" => Ok('b'),
+                _ => Err(ParseError::new(l, s, "Invalid register")),
+            }
+        };
+        let parse_offset = |s: &str| -> Result<i32, ParseError> {
+            let is_negative = match s.chars().next() {
+                Some('+') => false,
+                Some('-') => true,
+                _ => { return Err(ParseError::new(l, s, "Offset must start with '+' or '-'")); }
+            };
+            if !s[1..].bytes().all(|b| b.is_ascii_digit()) {
+                return Err(ParseError::new(l, s, "Invalid offset"));
+            }
+            let value = parse_value::<i32>(l, &s[1..])?;
+            Ok(value * (if is_negative { -1 } else { 1 }))
+        };
+        let parse_register_offset = |s: &str| -> Result<(char, i32), ParseError> {
+            let Some((r, offset)) = s.split_once(", ") else {
+                return Err(ParseError::new(l, s, "Expected '<register>, <offset>'"));
+            };
+            Ok((parse_register(r)?, parse_offset(offset)?))
+        };
+        match opcode {
+            "hlf" => Ok(Instruction::Half(parse_register(args)?)),
+            "tpl" => Ok(Instruction::Triple(parse_register(args)?)),
+            "inc" => Ok(Instruction::Increment(parse_register(args)?)),
+            "jmp" => Ok(Instruction::Jump(parse_offset(args)?)),
+            "jie" => {
+                let (r, offset) = parse_register_offset(args)?;
+                Ok(Instruction::JumpIfEven(r, offset))
+            },
+            "jio" => {
+                let (r, offset) = parse_register_offset(args)?;
+                Ok(Instruction::JumpIfOne(r, offset))
+            },
+            _ => Err(ParseError::new(l, opcode, "Invalid opcode")),
+        }
+    })
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Machine {
+    pub a: usize,
+    pub b: usize,
+    pub ip: usize,
+}
+
+impl Default for Machine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Machine {
+    pub fn new() -> Self {
+        Self { a: 0, b: 0, ip: 0 }
+    }
+
+    pub fn register(&self, r: char) -> usize {
+        match r {
+            'a' => self.a,
+            'b' => self.b,
+            _ => panic!("Invalid register"),
+        }
+    }
+
+    pub fn register_mut(&mut self, r: char) -> &mut usize {
+        match r {
+            'a' => &mut self.a,
+            'b' => &mut self.b,
+            _ => panic!("Invalid register"),
+        }
+    }
+
+    // Jumping before the first instruction leaves the program just like jumping past the last one
+    pub fn apply_offset(&mut self, offset: i32) {
+        self.ip = self.ip.checked_add_signed(offset as isize).unwrap_or(usize::MAX);
+    }
+
+    pub fn step(&mut self, program: &[Instruction]) -> bool {
+        if self.ip < program.len() {
+            let ip = self.ip;
+            match program[ip] {
+                Instruction::Half(r) => {
+                    *self.register_mut(r) /= 2;
+                    self.ip += 1;
+                },
+                Instruction::Triple(r) => {
+                    // Registers wrap around instead of growing without bounds in loops
+                    let register = self.register_mut(r);
+                    *register = register.wrapping_mul(3);
+                    self.ip += 1;
+                },
+                Instruction::Increment(r) => {
+                    let register = self.register_mut(r);
+                    *register = register.wrapping_add(1);
+                    self.ip += 1;
+                },
+                Instruction::Jump(offset) => {
+                    self.apply_offset(offset);
+                },
+                Instruction::JumpIfEven(r, offset) => {
+                    if self.register(r).is_multiple_of(2) {
+                        self.apply_offset(offset)
+                    } else {
+                        self.ip += 1;
+                    }
+                },
+                Instruction::JumpIfOne(r, offset) => {
+                    if self.register(r) == 1 {
+                        self.apply_offset(offset);
+                    } else {
+                        self.ip += 1;
+                    }
+                },
+            }
+            trace!("step", ip = ip, instruction = program[ip], a = self.a, b = self.b, next = self.ip);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+pub fn execute_program(program: &[Instruction]) -> (usize, usize) {
+    execute_program_within(program, &Budget::new()).done().expect("Unlimited budget")
+}
+
+// Programs with backward jumps can loop forever, a step is an instruction executed
+pub fn execute_program_within(program: &[Instruction], budget: &Budget) -> Outcome<(usize, usize)> {
+    let mut vm = Machine::new();
+    let mut steps = 0_u64;
+    while vm.step(program) {
+        steps += 1;
+        if steps.is_multiple_of(0x1000) && budget.is_exhausted() {
+            return Outcome::Stopped { partial: None, steps };
+        }
+    }
+    Outcome::Done((vm.a, vm.b))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        let p = parse_input(r"inc a
+jio a, +2
+tpl a
+inc a
+hlf b
+jie a, -32
+jmp -5").unwrap();
+        assert_eq!(p.len(), 7);
+        assert_eq!(p[0], Instruction::Increment('a'));
+        assert_eq!(p[1], Instruction::JumpIfOne('a', 2));
+        assert_eq!(p[2], Instruction::Triple('a'));
+        assert_eq!(p[3], Instruction::Increment('a'));
+        assert_eq!(p[4], Instruction::Half('b'));
+        assert_eq!(p[5], Instruction::JumpIfEven('a', -32));
+        assert_eq!(p[6], Instruction::Jump(-5));
+    }
+
+    #[test]
+    fn test_parse_input_errors() {
+        let e = parse_input("inc a\ninc c").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 5));
+        let e = parse_input("jie a,+2").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 5));
+        let e = parse_input("jmp 5").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 5));
+        let e = parse_input("jmp +-5").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 5));
+        let e = parse_input("nop a").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 1));
+        assert!(parse_input("inc").is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        let input = "inc a\njio a, +2\ntpl a\nhlf b\njie a, -32\njmp -5";
+        let text: Vec<_> = parse_input(input).unwrap().iter().map(|i| i.to_string()).collect();
+        assert_eq!(text.join("\n"), input);
+    }
+
+    #[test]
+    fn test_execute_program() {
+        let p = parse_input(r"inc a
+jio a, +2
+tpl a
+inc a
+hlf a
+jie a, +2
+inc b
+inc b
+jmp +2
+inc a
+tpl b").unwrap();
+        assert_eq!(execute_program(&p), (1, 6));
+    }
+
+    #[test]
+    fn test_execute_program_budget() {
+        let cancelled = Budget::new();
+        cancelled.cancel();
+        let program = parse_input("inc a\njmp +0").unwrap();
+        assert_eq!(execute_program_within(&program, &cancelled), Outcome::Stopped { partial: None, steps: 0x1000 });
+        let program = parse_input("inc a\ntpl a\ninc b").unwrap();
+        assert_eq!(execute_program_within(&program, &cancelled), Outcome::Done((3, 1)));
+    }
+
+    #[test]
+    fn test_leave_program() {
+        assert_eq!(execute_program(&parse_input("jmp -1\ninc a").unwrap()), (0, 0));
+        assert_eq!(execute_program(&parse_input("inc a\njie b, -2147483647\ninc b").unwrap()), (1, 0));
+        let mut vm = Machine::new();
+        vm.apply_offset(-1);
+        assert!(!vm.step(&[Instruction::Increment('a')]));
+    }
+
+    #[test]
+    fn test_registers_wrap() {
+        let mut vm = Machine { a: usize::MAX, b: usize::MAX / 2 + 1, ip: 0 };
+        let program = [Instruction::Increment('a'), Instruction::Triple('b')];
+        while vm.step(&program) {}
+        assert_eq!((vm.a, vm.b), (0, usize::MAX / 2 + 1));
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = Day23::generate(&mut Rng::new(seed), 200);
+            let program = Day23::parse(&input).unwrap();
+            Day23::part2(&program);
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use aoc_common::{parse_lines, parse_value, Configured, Example, Generator, Param, Params, ParseError, Rng, Solution};
+
+pub struct Day24;
+
+// From the puzzle description
+const SAMPLE: &str = r"1
+        2
+        3
+        4
+        5
+        7
+        8
+        9
+        10
+        11
+";
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    const NAME: &'static str = "it_hangs_in_the_balance";
+
+    const PARAMS: &'static [Param] = &[
+        Param::new("groups_part1", 3, "Number of equally heavy groups in part 1").range(1, i32::MAX as i64),
+        Param::new("groups_part2", 4, "Number of equally heavy groups in part 2").range(1, i32::MAX as i64),
+    ];
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(SAMPLE).part1("99").part2("44"),
+    ];
+
+    type Input = Configured<Vec<i32>>;
+    // `None` when the total weight is not a multiple of the number of groups, no group of weights makes up its
+    // share, or the quantum entanglement of every such group is too large for an i64. Whether the other weights
+    // can be split into the remaining groups is not checked.
+    type Answer1 = Option<i64>;
+    type Answer2 = Option<i64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, ParseError> {
+        Ok(Configured { value: parse_input(input)?, params: params.clone() })
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        find_smallest_group(&input.value, input.params.get_as("groups_part1"))
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        find_smallest_group(&input.value, input.params.get_as("groups_part2"))
+    }
+}
+
+impl Generator for Day24 {
+    const DEFAULT_SIZE: usize = 30;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // Split 12 equal groups into random positive parts, which makes the total
+        // divisible into both 3 and 4 groups of equal weight
+        let size = size.max(12);
+        let target = (size.div_ceil(12) * 10) as i64;
+        let mut weights = Vec::new();
+        for group in 0..12 {
+            let parts = size / 12 + usize::from(group < size % 12);
+            let mut cuts = vec![0, target];
+            while cuts.len() < parts + 1 {
+                let cut = rng.range(1..=target - 1);
+                if !cuts.contains(&cut) {
+                    cuts.push(cut);
+                }
+            }
+            cuts.sort();
+            weights.extend(cuts.windows(2).map(|w| w[1] - w[0]));
+        }
+        weights.sort();
+        weights.iter().map(|w| format!("{}\n", w)).collect()
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<i32>, ParseError> {
+    parse_lines(input, |l| {
+        let weight = parse_value::<i32>(l, l.trim())?;
+        if weight <= 0 {
+            return Err(ParseError::new(l, l.trim(), "Weight must be positive"));
+        }
+        Ok(weight)
+    })
+}
+
+fn find_group_rec(weights: &[i32], target: i32, nums: &mut Vec<i32>, smallest_known: &mut usize, results: &mut Vec<Vec<i32>>) {
+    if target == 0 {
+        *smallest_known = std::cmp::min(*smallest_known, nums.len());
+        results.push(nums.clone());
+    }
+    for i in 0..weights.len() {
+        let weights = &weights[..weights.len() - i];
+        let first = *weights.last().unwrap();
+        if first <= target
+            && nums.len() < *smallest_known {
+                nums.push(first);
+                find_group_rec(&weights[..weights.len() - 1], target - first, nums, smallest_known, results);
+                nums.pop();
+            }
+    }
+}
+
+pub fn calculate_qe(group: &[i32]) -> Option<i64> {
+    group.iter().try_fold(1_i64, |acc, &x| acc.checked_mul(x as i64))
+}
+
+pub fn find_smallest_group(weights: &[i32], n_groups: i32) -> Option<i64> {
+    let acc_sum = weights.iter().map(|&w| w as i64).sum::<i64>();
+    if acc_sum % n_groups as i64 != 0 {
+        return None;
+    }
+    let target = i32::try_from(acc_sum / n_groups as i64).ok()?;
+    let mut nums = Vec::new();
+    let mut smallest_known = usize::MAX;
+    let mut group1 = Vec::new();
+    find_group_rec(weights, target, &mut nums,&mut smallest_known, &mut group1);
+    group1.iter().filter_map(|g| calculate_qe(g)).min()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(parse_input(SAMPLE).unwrap(), vec![1, 2, 3, 4, 5, 7, 8, 9, 10, 11]);
+    }
+
+    #[test]
+    fn test_parse_input_errors() {
+        let e = parse_input("1\n2\nthree\n").unwrap_err();
+        assert_eq!((e.line, e.column), (3, 1));
+        assert!(parse_input("1\n-2\n").is_err());
+    }
+
+    #[test]
+    fn test_find_smallest_sum() {
+        let weights = parse_input(SAMPLE).unwrap();
+        assert_eq!(find_smallest_group(&weights, 3), Some(99));
+        assert_eq!(find_smallest_group(&weights, 4), Some(44));
+    }
+
+    #[test]
+    fn test_no_grouping() {
+        let weights = parse_input(SAMPLE).unwrap();
+        assert_eq!(find_smallest_group(&weights, 7), None);
+        assert_eq!(find_smallest_group(&[2, 2, 2], 2), None);
+        assert_eq!(find_smallest_group(&[i32::MAX, i32::MAX], 1), None);
+        assert_eq!(find_smallest_group(&[1 << 20; 4], 1), None);
+        assert_eq!(find_smallest_group(&[1 << 20; 4], 2), Some(1 << 40));
+
+        let mut params = Params::defaults(Day24::PARAMS);
+        params.set("groups_part1", 7).unwrap();
+        let input = Day24::parse_with(SAMPLE, &params).unwrap();
+        assert_eq!(aoc_common::Answer::to_answer(&Day24::part1(&input)), Some("none".into()));
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc_common::check_examples::<Day24>();
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = Day24::generate(&mut Rng::new(seed), 12);
+            let weights = Day24::parse(&input).unwrap();
+            Day24::part2(&weights);
+        }
+    }
+}
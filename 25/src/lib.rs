@@ -0,0 +1,133 @@
+use aoc_common::{parse_value, Generator, NoAnswer, ParseError, Rng, Solution};
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    const NAME: &'static str = "let_it_snow";
+
+    type Input = (i64, i64);
+    type Answer1 = i64;
+    type Answer2 = NoAnswer;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        let (row, col) = *input;
+        find_code(row, col)
+    }
+
+    fn part2(_input: &Self::Input) -> Self::Answer2 {
+        NoAnswer
+    }
+}
+
+impl Generator for Day25 {
+    const DEFAULT_SIZE: usize = 3000;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1) as i64;
+        format!("To continue, please consult the code grid in the manual.  Enter the code at row {}, column {}.\n",
+                rng.range(1..=size), rng.range(1..=size))
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<(i64, i64), ParseError> {
+    let input = input.trim();
+    let row = parse_coordinate(input, "row ", ",")?;
+    let col = parse_coordinate(input, "column ", ".")?;
+    Ok((row, col))
+}
+
+fn parse_coordinate(input: &str, prefix: &str, suffix: &str) -> Result<i64, ParseError> {
+    let start = input.find(prefix)
+        .ok_or_else(|| ParseError::new(input, input, format!("Missing '{}'", prefix.trim())))? + prefix.len();
+    let end = input[start..].find(suffix)
+        .ok_or_else(|| ParseError::new(input, &input[start..], format!("Expected '{}'", suffix)))? + start;
+    let value = parse_value::<i64>(input, &input[start..end])?;
+    if !(1..=100_000).contains(&value) {
+        return Err(ParseError::new(input, &input[start..end], format!("{} outside of 1..=100000", prefix.trim())));
+    }
+    Ok(value)
+}
+
+pub fn cantor_pairing(row: i64, col: i64) -> i64 {
+    let row = row - 1;
+    let col = col - 1;
+    col + ((row + col)*(row + col + 1)) / 2 + 1
+}
+
+pub fn find_code(row: i64, col: i64) -> i64 {
+    let limit = cantor_pairing(row, col) - 1;
+    let factor = 252533;
+    let divisor = 33554393;
+    let mut acc = 20151125;
+    for _ in 0..limit {
+        acc = (acc * factor) % divisor;
+    }
+    acc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(parse_input("To continue, please consult the code grid in the manual.  Enter the code at row 3010, column 3019.\n").unwrap(), (3010, 3019));
+        assert_eq!(parse_input("Enter the code at row 4, column 12345.").unwrap(), (4, 12345));
+        let e = parse_input("Enter the code at row 4, column x.").unwrap_err();
+        assert_eq!(e.column, 33);
+        assert!(parse_input("Enter the code at row 4").is_err());
+        assert!(parse_input("Enter the code at row 0, column 1.").is_err());
+    }
+
+    #[test]
+    fn test_cantor_pairing() {
+        assert_eq!(cantor_pairing(1, 1), 1);
+        assert_eq!(cantor_pairing(2, 1), 2);
+        assert_eq!(cantor_pairing(1, 2), 3);
+        assert_eq!(cantor_pairing(3, 1), 4);
+        assert_eq!(cantor_pairing(2, 2), 5);
+        assert_eq!(cantor_pairing(6, 1), 16);
+        assert_eq!(cantor_pairing(1, 5), 15);
+        assert_eq!(cantor_pairing(3, 3), 13);
+    }
+
+    // Walks the diagonals of the code grid the way the manual fills it in
+    fn naive_position(row: i64, col: i64) -> i64 {
+        let (mut r, mut c, mut n) = (1, 1, 1);
+        while (r, c) != (row, col) {
+            (r, c) = if r == 1 { (c + 1, 1) } else { (r - 1, c + 1) };
+            n += 1;
+        }
+        n
+    }
+
+    #[test]
+    fn test_cantor_pairing_reference() {
+        let generate = |rng: &mut Rng| parse_input(&Day25::generate(rng, 150)).unwrap();
+        aoc_common::check_against_reference(200, generate, |&(r, c)| cantor_pairing(r, c), |&(r, c)| naive_position(r, c));
+    }
+
+    #[test]
+    fn test_find_code() {
+        assert_eq!(find_code(1, 1), 20151125);
+        assert_eq!(find_code(1, 2), 18749137);
+        assert_eq!(find_code(1, 3), 17289845);
+        assert_eq!(find_code(1, 4), 30943339);
+        assert_eq!(find_code(1, 5), 10071777);
+        assert_eq!(find_code(4, 2), 32451966);
+        assert_eq!(find_code(6, 6), 27995004);
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = Day25::generate(&mut Rng::new(seed), 20);
+            assert!(Day25::parse(&input).is_ok(), "{}", input);
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use aoc_common::{parse_lines, parse_value, Answers, ParseError};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+// One line per answer: `<day> <part> <answer>`, e.g. `07 1 16076`
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct AnswerFile {
+    answers: BTreeMap<(u8, u8), String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Mismatch {
+    pub day: u8,
+    pub part: u8,
+    pub expected: Option<String>,
+    pub actual: Option<String>,
+}
+
+impl std::fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let show = |a: &Option<String>| a.clone().unwrap_or_else(|| "nothing".into());
+        match &self.expected {
+            Some(_) => write!(f, "Day {:02} part {}: expected {}, got {}", self.day, self.part, show(&self.expected), show(&self.actual)),
+            None => write!(f, "Day {:02} part {}: no recorded answer, got {}", self.day, self.part, show(&self.actual)),
+        }
+    }
+}
+
+impl AnswerFile {
+    pub fn parse(s: &str) -> Result<AnswerFile, ParseError> {
+        let mut answers = BTreeMap::new();
+        parse_lines(s, |l| {
+            if l.trim().is_empty() {
+                return Ok(());
+            }
+            let mut fields = l.splitn(3, ' ');
+            let (Some(day), Some(part), Some(answer)) = (fields.next(), fields.next(), fields.next()) else {
+                return Err(ParseError::new(l, l, "Expected '<day> <part> <answer>'"));
+            };
+            let part = parse_value::<u8>(l, part)?;
+            if part != 1 && part != 2 {
+                return Err(ParseError::new(l, &l[day.len() + 1..], "Part must be 1 or 2"));
+            }
+            answers.insert((parse_value(l, day)?, part), answer.to_string());
+            Ok(())
+        })?;
+        Ok(AnswerFile { answers })
+    }
+
+    // A missing file is treated as an empty set of answers
+    pub fn load(path: &Path) -> Result<AnswerFile, String> {
+        match std::fs::read_to_string(path) {
+            Ok(s) => AnswerFile::parse(&s).map_err(|e| e.with_file(path).to_string()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(AnswerFile::default()),
+            Err(e) => Err(format!("Error reading {}: {}", path.display(), e)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        std::fs::write(path, self.to_string())
+    }
+
+    pub fn record(&mut self, day: u8, (answer1, answer2): &Answers) {
+        self.answers.insert((day, 1), answer1.clone());
+        match answer2 {
+            Some(a) => self.answers.insert((day, 2), a.clone()),
+            None => self.answers.remove(&(day, 2)),
+        };
+    }
+
+    pub fn verify(&self, day: u8, (answer1, answer2): &Answers) -> Vec<Mismatch> {
+        let mut mismatches = Vec::new();
+        for (part, actual) in [(1, Some(answer1)), (2, answer2.as_ref())] {
+            let expected = self.answers.get(&(day, part));
+            if expected != actual {
+                mismatches.push(Mismatch { day, part, expected: expected.cloned(), actual: actual.cloned() });
+            }
+        }
+        mismatches
+    }
+}
+
+impl std::fmt::Display for AnswerFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for ((day, part), answer) in &self.answers {
+            writeln!(f, "{:02} {} {}", day, part, answer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let a = AnswerFile::parse("07 1 16076\n07 2 2797\n\n11 1 vzbxxyzz\n").unwrap();
+        assert_eq!(a.answers.get(&(7, 2)).unwrap(), "2797");
+        assert_eq!(a.answers.get(&(11, 1)).unwrap(), "vzbxxyzz");
+        assert_eq!(a.to_string(), "07 1 16076\n07 2 2797\n11 1 vzbxxyzz\n");
+        assert_eq!(AnswerFile::parse("07 3 1").unwrap_err().column, 4);
+        assert_eq!(AnswerFile::parse("07 1 1\n7 1").unwrap_err().line, 2);
+        assert!(AnswerFile::parse("x 1 1").is_err());
+    }
+
+    #[test]
+    fn test_record_and_verify() {
+        let mut a = AnswerFile::default();
+        a.record(25, &("8997277".into(), None));
+        a.record(7, &("16076".into(), Some("2797".into())));
+        assert_eq!(a.verify(7, &("16076".into(), Some("2797".into()))), vec![]);
+        assert_eq!(a.verify(25, &("8997277".into(), None)), vec![]);
+
+        let mismatches = a.verify(7, &("16076".into(), Some("1".into())));
+        assert_eq!(mismatches, vec![Mismatch { day: 7, part: 2, expected: Some("2797".into()), actual: Some("1".into()) }]);
+        assert_eq!(mismatches[0].to_string(), "Day 07 part 2: expected 2797, got 1");
+
+        let mismatches = a.verify(8, &("1".into(), None));
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].to_string(), "Day 08 part 1: no recorded answer, got 1");
+    }
+}
@@ -0,0 +1,503 @@
+use aoc_common::InputSource;
+use std::path::PathBuf;
+use std::time::Duration;
+
+pub const USAGE: &str = "Usage:
+    aoc2015 run <DAY|all> [--input PATH | --input-str INPUT] [--json] [--trace text|json] [--visualize [--fps N]]
+                [--timeout SECONDS] [PARAMS]
+    aoc2015 bench <DAY|all> [--input PATH | --input-str INPUT] [--runs N] [PARAMS]
+    aoc2015 record <DAY|all> [--input PATH | --input-str INPUT] [--answers PATH]
+    aoc2015 verify <DAY|all> [--input PATH | --input-str INPUT] [--answers PATH]
+    aoc2015 example <DAY|all>
+    aoc2015 summary <DAY|all> [--input PATH | --input-str INPUT] [--threads N] [PARAMS]
+    aoc2015 report <DAY|all> [--input PATH | --input-str INPUT] [--format markdown|html] [--output PATH] [--threads N]
+                   [PARAMS]
+    aoc2015 params <DAY|all> [PARAMS]
+    aoc2015 generate <DAY> [--seed N] [--size N]
+    aoc2015 serve [--port N] [--timeout SECONDS] [PARAMS]
+    aoc2015 fetch <DAY|all> [--base-url URL] [--cache-dir PATH] [--session-file PATH]
+    aoc2015 help
+
+PARAMS: [--config PATH] [--set DAY.NAME=VALUE]...";
+
+pub const DEFAULT_RUNS: usize = 5;
+
+// Time `serve` gives a solve before answering with a 503
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Selection {
+    Day(u8),
+    All,
+}
+
+// Events emitted by the solvers while `--trace` is given
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceFormat {
+    Text,
+    Json,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Markdown,
+    Html,
+}
+
+// Where the puzzle parameters come from: params.txt in the workspace unless `--config` is given, `--set` wins
+// over both
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Overrides {
+    pub config: Option<PathBuf>,
+    pub sets: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Run {
+        selection: Selection, input: Option<InputSource>, json: bool, trace: Option<TraceFormat>, visualize: Option<u32>,
+        timeout: Option<Duration>, overrides: Overrides,
+    },
+    Bench { selection: Selection, input: Option<InputSource>, runs: usize, overrides: Overrides },
+    Record { selection: Selection, input: Option<InputSource>, answers: Option<PathBuf> },
+    Verify { selection: Selection, input: Option<InputSource>, answers: Option<PathBuf> },
+    Summary { selection: Selection, input: Option<InputSource>, threads: Option<usize>, overrides: Overrides },
+    Report {
+        selection: Selection, input: Option<InputSource>, format: ReportFormat, output: Option<PathBuf>, threads: Option<usize>,
+        overrides: Overrides,
+    },
+    Params { selection: Selection, overrides: Overrides },
+    Example { selection: Selection },
+    Generate { day: u8, seed: u64, size: Option<usize> },
+    Serve { port: u16, timeout: Duration, overrides: Overrides },
+    Fetch { selection: Selection, base_url: Option<String>, cache_dir: Option<PathBuf>, session_file: Option<PathBuf> },
+    Help,
+}
+
+fn parse_selection(s: &str) -> Result<Selection, String> {
+    if s == "all" {
+        return Ok(Selection::All);
+    }
+    match s.parse::<u8>() {
+        Ok(n) if (1..=25).contains(&n) => Ok(Selection::Day(n)),
+        _ => Err(format!("Invalid day '{}', expected 1 to 25 or 'all'", s)),
+    }
+}
+
+struct DayArgs {
+    selection: Selection,
+    input: Option<InputSource>,
+    runs: Option<usize>,
+    answers: Option<PathBuf>,
+    json: bool,
+    threads: Option<usize>,
+    trace: Option<TraceFormat>,
+    visualize: bool,
+    fps: Option<u32>,
+    timeout: Option<Duration>,
+    format: Option<ReportFormat>,
+    output: Option<PathBuf>,
+    overrides: Overrides,
+}
+
+// Seconds, fractions allowed
+fn parse_timeout(value: Option<String>) -> Result<Duration, String> {
+    let secs = value.ok_or("Missing seconds for '--timeout'")?;
+    secs.parse::<f64>().ok()
+        .and_then(|s| Duration::try_from_secs_f64(s).ok())
+        .ok_or_else(|| format!("Invalid timeout '{}'", secs))
+}
+
+// `--config PATH` or `--set DAY.NAME=VALUE`
+fn parse_override(overrides: &mut Overrides, option: &str, value: Option<String>) -> Result<(), String> {
+    if option == "--config" {
+        overrides.config = Some(PathBuf::from(value.ok_or("Missing path for '--config'")?));
+        return Ok(());
+    }
+    let setting = value.ok_or("Missing 'DAY.NAME=VALUE' for '--set'")?;
+    if !setting.contains('=') {
+        return Err(format!("Invalid setting '{}', expected 'DAY.NAME=VALUE'", setting));
+    }
+    overrides.sets.push(setting);
+    Ok(())
+}
+
+// Parses `<DAY|all>` followed by any of the `allowed` options
+fn parse_day_args<I: Iterator<Item = String>>(command: &str, allowed: &[&str], mut args: I) -> Result<DayArgs, String> {
+    let selection = parse_selection(&args.next().ok_or_else(|| format!("Missing day for '{}'", command))?)?;
+    let mut day_args = DayArgs {
+        selection, input: None, runs: None, answers: None, json: false, threads: None, trace: None, visualize: false, fps: None,
+        timeout: None, format: None, output: None, overrides: Overrides::default(),
+    };
+    while let Some(arg) = args.next() {
+        if arg.starts_with("--") && !allowed.contains(&arg.as_str()) {
+            return Err(format!("'{}' can not be used with '{}'", arg, command));
+        }
+        match arg.as_str() {
+            // `-` is stdin
+            "--input" => {
+                let path = args.next().ok_or("Missing path for '--input'")?;
+                day_args.input = Some(if path == "-" { InputSource::Stdin } else { InputSource::File(path.into()) });
+            },
+            "--input-str" => {
+                day_args.input = Some(InputSource::Inline(args.next().ok_or("Missing input for '--input-str'")?));
+            },
+            "--runs" => {
+                let n = args.next().ok_or("Missing count for '--runs'")?;
+                match n.parse::<usize>() {
+                    Ok(n) if n > 0 => day_args.runs = Some(n),
+                    _ => return Err(format!("Invalid run count '{}'", n)),
+                }
+            },
+            "--json" => day_args.json = true,
+            "--trace" => {
+                day_args.trace = match args.next().as_deref() {
+                    Some("text") => Some(TraceFormat::Text),
+                    Some("json") => Some(TraceFormat::Json),
+                    Some(f) => return Err(format!("Invalid trace format '{}', expected 'text' or 'json'", f)),
+                    None => return Err("Missing format for '--trace'".into()),
+                };
+            },
+            "--visualize" => day_args.visualize = true,
+            "--fps" => {
+                let n = args.next().ok_or("Missing rate for '--fps'")?;
+                day_args.fps = Some(n.parse().map_err(|_| format!("Invalid frame rate '{}'", n))?);
+            },
+            "--timeout" => day_args.timeout = Some(parse_timeout(args.next())?),
+            "--threads" => {
+                let n = args.next().ok_or("Missing count for '--threads'")?;
+                match n.parse::<usize>() {
+                    Ok(n) if n > 0 => day_args.threads = Some(n),
+                    _ => return Err(format!("Invalid thread count '{}'", n)),
+                }
+            },
+            "--format" => {
+                day_args.format = match args.next().as_deref() {
+                    Some("markdown") => Some(ReportFormat::Markdown),
+                    Some("html") => Some(ReportFormat::Html),
+                    Some(f) => return Err(format!("Invalid report format '{}', expected 'markdown' or 'html'", f)),
+                    None => return Err("Missing format for '--format'".into()),
+                };
+            },
+            "--output" => {
+                day_args.output = Some(PathBuf::from(args.next().ok_or("Missing path for '--output'")?));
+            },
+            "--answers" => {
+                day_args.answers = Some(PathBuf::from(args.next().ok_or("Missing path for '--answers'")?));
+            },
+            "--config" | "--set" => parse_override(&mut day_args.overrides, &arg, args.next())?,
+            _ => return Err(format!("Unexpected argument '{}'", arg)),
+        }
+    }
+    if day_args.input.is_some() && day_args.selection == Selection::All {
+        return Err("An explicit input can only be used with a single day".into());
+    }
+    Ok(day_args)
+}
+
+fn parse_run<I: Iterator<Item = String>>(args: I) -> Result<Command, String> {
+    let allowed = ["--input", "--input-str", "--json", "--trace", "--visualize", "--fps", "--timeout", "--config", "--set"];
+    let DayArgs { selection, input, json, trace, visualize, fps, timeout, overrides, .. } = parse_day_args("run", &allowed, args)?;
+    // `--visualize` shows the progress of days 03, 06, 14 and 18 at `--fps` frames per second, 0 for as fast as
+    // possible. `--timeout` stops the searches of days 04, 13, 19 and 22 after the given time for each day, they
+    // print how far they got instead of the answer.
+    if fps.is_some() && !visualize {
+        return Err("'--fps' can only be used with '--visualize'".into());
+    }
+    // Frames go to stdout, they would end up between the lines of the other output
+    if visualize && (json || trace.is_some()) {
+        return Err("'--visualize' can not be combined with '--json' or '--trace'".into());
+    }
+    let visualize = visualize.then(|| fps.unwrap_or(aoc_render::DEFAULT_FPS));
+    Ok(Command::Run { selection, input, json, trace, visualize, timeout, overrides })
+}
+
+fn parse_bench<I: Iterator<Item = String>>(args: I) -> Result<Command, String> {
+    let allowed = ["--input", "--input-str", "--runs", "--config", "--set"];
+    let DayArgs { selection, input, runs, overrides, .. } = parse_day_args("bench", &allowed, args)?;
+    Ok(Command::Bench { selection, input, runs: runs.unwrap_or(DEFAULT_RUNS), overrides })
+}
+
+// `record` and `verify` always use the default parameters
+fn parse_record<I: Iterator<Item = String>>(args: I) -> Result<Command, String> {
+    let DayArgs { selection, input, answers, .. } = parse_day_args("record", &["--input", "--input-str", "--answers"], args)?;
+    Ok(Command::Record { selection, input, answers })
+}
+
+fn parse_verify<I: Iterator<Item = String>>(args: I) -> Result<Command, String> {
+    let DayArgs { selection, input, answers, .. } = parse_day_args("verify", &["--input", "--input-str", "--answers"], args)?;
+    Ok(Command::Verify { selection, input, answers })
+}
+
+fn parse_summary<I: Iterator<Item = String>>(args: I) -> Result<Command, String> {
+    let allowed = ["--input", "--input-str", "--threads", "--config", "--set"];
+    let DayArgs { selection, input, threads, overrides, .. } = parse_day_args("summary", &allowed, args)?;
+    Ok(Command::Summary { selection, input, threads, overrides })
+}
+
+// `report` writes the answers, timings and input sizes of the days, with pictures such as day 18's final grid,
+// to `--output` or to stdout without it
+fn parse_report<I: Iterator<Item = String>>(args: I) -> Result<Command, String> {
+    let allowed = ["--input", "--input-str", "--format", "--output", "--threads", "--config", "--set"];
+    let DayArgs { selection, input, format, output, threads, overrides, .. } = parse_day_args("report", &allowed, args)?;
+    Ok(Command::Report { selection, input, format: format.unwrap_or(ReportFormat::Markdown), output, threads, overrides })
+}
+
+fn parse_example<I: Iterator<Item = String>>(args: I) -> Result<Command, String> {
+    let DayArgs { selection, .. } = parse_day_args("example", &[], args)?;
+    Ok(Command::Example { selection })
+}
+
+fn parse_params<I: Iterator<Item = String>>(args: I) -> Result<Command, String> {
+    let DayArgs { selection, overrides, .. } = parse_day_args("params", &["--config", "--set"], args)?;
+    Ok(Command::Params { selection, overrides })
+}
+
+fn parse_number<T: std::str::FromStr>(option: &str, value: Option<String>) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("Missing value for '{}'", option))?;
+    value.parse().map_err(|_| format!("Invalid value '{}' for '{}'", value, option))
+}
+
+fn parse_generate<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+    let day = match parse_selection(&args.next().ok_or("Missing day for 'generate'")?)? {
+        Selection::Day(n) => n,
+        Selection::All => return Err("'generate' works on a single day".into()),
+    };
+    let mut seed = 0;
+    let mut size = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => seed = parse_number(&arg, args.next())?,
+            "--size" => size = Some(parse_number(&arg, args.next())?),
+            _ => return Err(format!("Unexpected argument '{}'", arg)),
+        }
+    }
+    Ok(Command::Generate { day, seed, size })
+}
+
+// `serve` answers `POST /day/<DAY>/part/<PART>` with the input as body on localhost, port 0 picks a free port.
+// Parameters can also be changed per request with `?name=value`.
+fn parse_serve<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+    let mut port = aoc_server::DEFAULT_PORT;
+    let mut timeout = DEFAULT_REQUEST_TIMEOUT;
+    let mut overrides = Overrides::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--port" => port = parse_number(&arg, args.next())?,
+            "--timeout" => timeout = parse_timeout(args.next())?,
+            "--config" | "--set" => parse_override(&mut overrides, &arg, args.next())?,
+            _ => return Err(format!("Unexpected argument '{}'", arg)),
+        }
+    }
+    Ok(Command::Serve { port, timeout, overrides })
+}
+
+// `fetch` takes the session token from AOC_SESSION unless a session file is given, and the base URL from
+// AOC_BASE_URL unless `--base-url` is given
+fn parse_fetch<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+    let selection = parse_selection(&args.next().ok_or("Missing day for 'fetch'")?)?;
+    let (mut base_url, mut cache_dir, mut session_file) = (None, None, None);
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("Missing value for '{}'", arg));
+        match arg.as_str() {
+            "--base-url" => base_url = Some(value()?),
+            "--cache-dir" => cache_dir = Some(PathBuf::from(value()?)),
+            "--session-file" => session_file = Some(PathBuf::from(value()?)),
+            _ => return Err(format!("Unexpected argument '{}'", arg)),
+        }
+    }
+    Ok(Command::Fetch { selection, base_url, cache_dir, session_file })
+}
+
+pub fn parse_args<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+    match args.next().as_deref() {
+        Some("run") => parse_run(args),
+        Some("bench") => parse_bench(args),
+        Some("record") => parse_record(args),
+        Some("verify") => parse_verify(args),
+        Some("summary") => parse_summary(args),
+        Some("report") => parse_report(args),
+        Some("params") => parse_params(args),
+        Some("example") => parse_example(args),
+        Some("generate") => parse_generate(args),
+        Some("serve") => parse_serve(args),
+        Some("fetch") => parse_fetch(args),
+        Some("help") | Some("--help") | Some("-h") | None => Ok(Command::Help),
+        Some(c) => Err(format!("Unknown command '{}'", c)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Result<Command, String> {
+        parse_args(s.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(parse(""), Ok(Command::Help));
+        assert_eq!(parse("run 07"), Ok(Command::Run { selection: Selection::Day(7), input: None, json: false, trace: None, visualize: None, timeout: None, overrides: Overrides::default() }));
+        assert_eq!(parse("run 7 --input foo/bar"), Ok(Command::Run { selection: Selection::Day(7), input: Some(InputSource::File("foo/bar".into())), json: false, trace: None, visualize: None, timeout: None, overrides: Overrides::default() }));
+        assert_eq!(parse("run all"), Ok(Command::Run { selection: Selection::All, input: None, json: false, trace: None, visualize: None, timeout: None, overrides: Overrides::default() }));
+        assert_eq!(parse("run all --json"), Ok(Command::Run { selection: Selection::All, input: None, json: true, trace: None, visualize: None, timeout: None, overrides: Overrides::default() }));
+        assert!(parse("run").is_err());
+        assert!(parse("run 0").is_err());
+        assert!(parse("run 26").is_err());
+        assert_eq!(parse("run 7 --input -"), Ok(Command::Run { selection: Selection::Day(7), input: Some(InputSource::Stdin), json: false, trace: None, visualize: None, timeout: None, overrides: Overrides::default() }));
+        assert_eq!(parse("run 4 --input-str abcdef"),
+                   Ok(Command::Run { selection: Selection::Day(4), input: Some(InputSource::Inline("abcdef".into())), json: false, trace: None, visualize: None, timeout: None, overrides: Overrides::default() }));
+        assert!(parse("run 07 --input").is_err());
+        assert!(parse("run 07 --input-str").is_err());
+        assert!(parse("run all --input-str abc").is_err());
+        assert!(parse("run all --input foo").is_err());
+        assert!(parse("run 7 --runs 3").is_err());
+        assert_eq!(parse("run 23 --trace json"), Ok(Command::Run { selection: Selection::Day(23), input: None, json: false, trace: Some(TraceFormat::Json), visualize: None, timeout: None, overrides: Overrides::default() }));
+        assert_eq!(parse("run all --json --trace text"), Ok(Command::Run { selection: Selection::All, input: None, json: true, trace: Some(TraceFormat::Text), visualize: None, timeout: None, overrides: Overrides::default() }));
+        assert!(parse("run 23 --trace").is_err());
+        assert!(parse("run 23 --trace xml").is_err());
+        assert!(parse("bench 23 --trace text").is_err());
+        assert!(parse("frobnicate").is_err());
+    }
+
+    #[test]
+    fn test_parse_visualize_args() {
+        assert_eq!(parse("run 18 --visualize"), Ok(Command::Run { selection: Selection::Day(18), input: None, json: false, trace: None, visualize: Some(aoc_render::DEFAULT_FPS), timeout: None, overrides: Overrides::default() }));
+        assert_eq!(parse("run all --fps 0 --visualize"), Ok(Command::Run { selection: Selection::All, input: None, json: false, trace: None, visualize: Some(0), timeout: None, overrides: Overrides::default() }));
+        assert!(parse("run 18 --fps 10").is_err());
+        assert!(parse("run 18 --visualize --fps").is_err());
+        assert!(parse("run 18 --visualize --fps -1").is_err());
+        assert!(parse("run 18 --visualize --json").is_err());
+        assert!(parse("run 18 --visualize --trace text").is_err());
+        assert!(parse("bench 18 --visualize").is_err());
+    }
+
+    #[test]
+    fn test_parse_timeout_args() {
+        assert!(matches!(parse("run 04 --timeout 2.5"), Ok(Command::Run { timeout: Some(t), .. }) if t == Duration::from_millis(2500)));
+        assert!(matches!(parse("run all --timeout 0"), Ok(Command::Run { timeout: Some(Duration::ZERO), .. })));
+        assert!(parse("run 04 --timeout").is_err());
+        assert!(parse("run 04 --timeout -1").is_err());
+        assert!(parse("run 04 --timeout soon").is_err());
+        assert!(parse("bench 04 --timeout 1").is_err());
+    }
+
+    #[test]
+    fn test_parse_bench_args() {
+        assert_eq!(parse("bench all"), Ok(Command::Bench { selection: Selection::All, input: None, runs: DEFAULT_RUNS, overrides: Overrides::default() }));
+        assert_eq!(parse("bench 4 --runs 10"), Ok(Command::Bench { selection: Selection::Day(4), input: None, runs: 10, overrides: Overrides::default() }));
+        assert_eq!(parse("bench 9 --runs 1 --input x"), Ok(Command::Bench { selection: Selection::Day(9), input: Some(InputSource::File("x".into())), runs: 1, overrides: Overrides::default() }));
+        assert!(parse("bench").is_err());
+        assert!(parse("bench all --runs 0").is_err());
+        assert!(parse("bench all --runs many").is_err());
+        assert!(parse("bench all --runs").is_err());
+        assert!(parse("bench all --answers foo").is_err());
+        assert!(parse("bench all --json").is_err());
+    }
+
+    #[test]
+    fn test_parse_generate_args() {
+        assert_eq!(parse("generate 7"), Ok(Command::Generate { day: 7, seed: 0, size: None }));
+        assert_eq!(parse("generate 18 --seed 42 --size 500"), Ok(Command::Generate { day: 18, seed: 42, size: Some(500) }));
+        assert!(parse("generate all").is_err());
+        assert!(parse("generate 7 --seed").is_err());
+        assert!(parse("generate 7 --size -1").is_err());
+        assert!(parse("generate 7 --input x").is_err());
+    }
+
+    #[test]
+    fn test_parse_serve_args() {
+        assert_eq!(parse("serve"), Ok(Command::Serve {
+            port: aoc_server::DEFAULT_PORT, timeout: DEFAULT_REQUEST_TIMEOUT, overrides: Overrides::default(),
+        }));
+        assert_eq!(parse("serve --port 0 --timeout 0.5 --set 14.seconds=10 --config p.txt"), Ok(Command::Serve {
+            port: 0,
+            timeout: Duration::from_millis(500),
+            overrides: Overrides { config: Some("p.txt".into()), sets: vec!["14.seconds=10".into()] },
+        }));
+        assert!(parse("serve --port").is_err());
+        assert!(parse("serve --port 70000").is_err());
+        assert!(parse("serve --timeout").is_err());
+        assert!(parse("serve --timeout -3").is_err());
+        assert!(parse("serve --set 14.seconds").is_err());
+        assert!(parse("serve 14").is_err());
+    }
+
+    #[test]
+    fn test_parse_fetch_args() {
+        assert_eq!(parse("fetch all"), Ok(Command::Fetch { selection: Selection::All, base_url: None, cache_dir: None, session_file: None }));
+        assert_eq!(parse("fetch 7 --base-url http://localhost:8080 --cache-dir c --session-file s"),
+                   Ok(Command::Fetch { selection: Selection::Day(7), base_url: Some("http://localhost:8080".into()),
+                                       cache_dir: Some("c".into()), session_file: Some("s".into()) }));
+        assert!(parse("fetch").is_err());
+        assert!(parse("fetch 7 --base-url").is_err());
+        assert!(parse("fetch 7 --input x").is_err());
+    }
+
+    #[test]
+    fn test_parse_record_verify_args() {
+        assert_eq!(parse("record all"), Ok(Command::Record { selection: Selection::All, input: None, answers: None }));
+        assert_eq!(parse("record 3 --input in --answers out"),
+                   Ok(Command::Record { selection: Selection::Day(3), input: Some(InputSource::File("in".into())), answers: Some("out".into()) }));
+        assert_eq!(parse("verify all --answers a.txt"), Ok(Command::Verify { selection: Selection::All, input: None, answers: Some("a.txt".into()) }));
+        assert!(parse("verify all --answers").is_err());
+        assert!(parse("verify all --runs 3").is_err());
+        assert!(parse("record").is_err());
+    }
+
+    #[test]
+    fn test_parse_summary_args() {
+        assert_eq!(parse("summary all"), Ok(Command::Summary { selection: Selection::All, input: None, threads: None, overrides: Overrides::default() }));
+        assert_eq!(parse("summary all --threads 4"), Ok(Command::Summary { selection: Selection::All, input: None, threads: Some(4), overrides: Overrides::default() }));
+        assert_eq!(parse("summary 7 --input x"), Ok(Command::Summary { selection: Selection::Day(7), input: Some(InputSource::File("x".into())), threads: None, overrides: Overrides::default() }));
+        assert!(parse("summary all --threads 0").is_err());
+        assert!(parse("summary all --threads").is_err());
+        assert!(parse("summary all --json").is_err());
+        assert!(parse("run all --threads 2").is_err());
+    }
+
+    #[test]
+    fn test_parse_report_args() {
+        assert_eq!(parse("report all"), Ok(Command::Report {
+            selection: Selection::All, input: None, format: ReportFormat::Markdown, output: None, threads: None,
+            overrides: Overrides::default(),
+        }));
+        assert_eq!(parse("report 18 --format html --output r.html --threads 2 --set 18.steps=5"), Ok(Command::Report {
+            selection: Selection::Day(18), input: None, format: ReportFormat::Html, output: Some("r.html".into()), threads: Some(2),
+            overrides: Overrides { config: None, sets: vec!["18.steps=5".into()] },
+        }));
+        assert!(parse("report all --format").is_err());
+        assert!(parse("report all --format pdf").is_err());
+        assert!(parse("report all --output").is_err());
+        assert!(parse("report all --json").is_err());
+        assert!(parse("summary all --format html").is_err());
+    }
+
+    #[test]
+    fn test_parse_example_args() {
+        assert_eq!(parse("example all"), Ok(Command::Example { selection: Selection::All }));
+        assert_eq!(parse("example 18"), Ok(Command::Example { selection: Selection::Day(18) }));
+        assert!(parse("example").is_err());
+        assert!(parse("example 18 --input x").is_err());
+        assert!(parse("example 18 --set 18.steps=4").is_err());
+    }
+
+    #[test]
+    fn test_parse_overrides() {
+        let overrides = Overrides { config: Some("p.txt".into()), sets: vec!["14.seconds=1000".into(), "24.groups_part1=5".into()] };
+        assert_eq!(parse("params 14 --config p.txt --set 14.seconds=1000 --set 24.groups_part1=5"),
+                   Ok(Command::Params { selection: Selection::Day(14), overrides }));
+        assert_eq!(parse("bench all --set 18.steps=4"),
+                   Ok(Command::Bench { selection: Selection::All, input: None, runs: DEFAULT_RUNS,
+                                       overrides: Overrides { config: None, sets: vec!["18.steps=4".into()] } }));
+        assert!(matches!(parse("run 14 --set 14.seconds=10"), Ok(Command::Run { overrides: Overrides { config: None, .. }, .. })));
+        assert!(matches!(parse("summary all --config c"), Ok(Command::Summary { overrides: Overrides { config: Some(_), .. }, .. })));
+        assert!(parse("params 14 --set 14.seconds").is_err());
+        assert!(parse("params 14 --set").is_err());
+        assert!(parse("params 14 --config").is_err());
+        assert!(parse("params 14 --input x").is_err());
+        assert!(parse("verify all --set 14.seconds=10").is_err());
+        assert!(parse("record all --config c").is_err());
+    }
+}
@@ -0,0 +1,87 @@
+use crate::days::{self, Day};
+use aoc_common::{parse_lines, parse_value, Params, ParseError};
+use std::path::Path;
+
+// Parameters for every day, changed by lines of `<day>.<name> = <value>`, e.g. `14.seconds = 1000`.
+// Empty lines and lines starting with `#` are ignored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    params: Vec<Params>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Config {
+    pub fn new() -> Config {
+        Config { params: days::DAYS.iter().map(|d| Params::defaults(d.params)).collect() }
+    }
+
+    pub fn parse(s: &str) -> Result<Config, ParseError> {
+        let mut config = Config::new();
+        parse_lines(s, |l| {
+            let trimmed = l.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                return Ok(());
+            }
+            config.set(l)
+        })?;
+        Ok(config)
+    }
+
+    pub fn load(path: &Path) -> Result<Config, String> {
+        let s = std::fs::read_to_string(path).map_err(|e| format!("Error reading {}: {}", path.display(), e))?;
+        Config::parse(&s).map_err(|e| e.with_file(path).to_string())
+    }
+
+    // Applies a single `<day>.<name> = <value>` line
+    pub fn set(&mut self, line: &str) -> Result<(), ParseError> {
+        let expected = || ParseError::new(line, line, "Expected '<day>.<name> = <value>'");
+        let (key, value) = line.split_once('=').ok_or_else(expected)?;
+        let (day, name) = key.trim().split_once('.').ok_or_else(expected)?;
+        let (day, name, text) = (day.trim(), name.trim(), value.trim());
+        let number = parse_value::<u8>(line, day)?;
+        let Some(params) = self.params.get_mut(usize::from(number).wrapping_sub(1)) else {
+            return Err(ParseError::new(line, day, format!("Unknown day '{}'", day)));
+        };
+        let value = parse_value(line, text)?;
+        params.set(name, value).map_err(|e| {
+            let known = params.specs().iter().any(|p| p.name == name);
+            ParseError::new(line, if known { text } else { name }, e)
+        })
+    }
+
+    pub fn params(&self, day: &Day) -> &Params {
+        &self.params[usize::from(day.number) - 1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let config = Config::parse("# Shorter race\n14.seconds = 1000\n\n 24.groups_part2=5\n").unwrap();
+        assert_eq!(config.params(days::find(14).unwrap()).get("seconds"), 1000);
+        assert_eq!(config.params(days::find(24).unwrap()).get("groups_part1"), 3);
+        assert_eq!(config.params(days::find(24).unwrap()).get("groups_part2"), 5);
+        assert_eq!(Config::parse("").unwrap(), Config::new());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |s: &str| Config::parse(s).map(|_| ()).map_err(|e| (e.line, e.column, e.message));
+        assert_eq!(error("\n14.seconds"), Err((2, 1, "Expected '<day>.<name> = <value>'".into())));
+        assert_eq!(error("seconds = 3"), Err((1, 1, "Expected '<day>.<name> = <value>'".into())));
+        assert_eq!(error("26.seconds = 3"), Err((1, 1, "Unknown day '26'".into())));
+        assert_eq!(error("x.seconds = 3"), Err((1, 1, "Invalid value 'x'".into())));
+        assert_eq!(error("14.seconds = many"), Err((1, 14, "Invalid value 'many'".into())));
+        assert_eq!(error("14.second = 3"), Err((1, 4, "Unknown parameter 'second', expected one of seconds".into())));
+        assert_eq!(error("2.floor = 3"), Err((1, 3, "Unknown parameter 'floor', there are none".into())));
+        assert_eq!(error("14.seconds = -1"), Err((1, 14, format!("Parameter 'seconds' must be between 0 and {}", u32::MAX))));
+    }
+}
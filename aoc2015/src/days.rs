@@ -0,0 +1,134 @@
+use aoc_common::{Example, ExampleAnswers, Generator, InputSource, Param, Params, ParseError, Rng, Solved, Timings};
+use std::path::{Path, PathBuf};
+
+pub enum DefaultInput {
+    File(&'static str),
+    Inline(&'static str),
+}
+
+pub struct Day {
+    pub number: u8,
+    pub name: &'static str,
+    pub input: DefaultInput,
+    pub params: &'static [Param],
+    pub solve: fn(&str, &Params) -> Result<Solved, ParseError>,
+    pub solve_part: fn(&str, &Params, u8) -> Result<Option<String>, ParseError>,
+    pub picture: fn(&str, &Params) -> Result<Option<String>, ParseError>,
+    pub bench: fn(&str, &Params, usize) -> Result<Timings, ParseError>,
+    pub examples: &'static [Example],
+    pub solve_example: fn(&Example) -> Result<ExampleAnswers, ParseError>,
+    pub generate: fn(&mut Rng, usize) -> String,
+    pub default_size: usize,
+    #[cfg(test)]
+    pub check_robust: fn(u64),
+}
+
+const fn day<S: Generator>(input: DefaultInput) -> Day {
+    Day {
+        number: S::DAY,
+        name: S::NAME,
+        input,
+        params: S::PARAMS,
+        solve: aoc_common::solve_timed::<S>,
+        solve_part: aoc_common::solve_part::<S>,
+        picture: aoc_common::picture::<S>,
+        bench: aoc_common::bench::<S>,
+        examples: S::EXAMPLES,
+        solve_example: aoc_common::solve_example::<S>,
+        generate: S::generate,
+        default_size: S::DEFAULT_SIZE,
+        #[cfg(test)]
+        check_robust: aoc_common::check_robust::<S>,
+    }
+}
+
+pub const DAYS: [Day; 25] = [
+    day::<not_quite_lisp::Day01>(DefaultInput::File("01/input.txt")),
+    day::<i_was_told_there_would_be_no_math::Day02>(DefaultInput::File("02/input")),
+    day::<perfectly_spherical_houses_in_a_vacuum::Day03>(DefaultInput::File("03/input")),
+    day::<the_ideal_stocking_stuffer::Day04>(DefaultInput::Inline("yzbqklnj")),
+    day::<doesnt_he_have_internelves_for_this::Day05>(DefaultInput::File("05/input")),
+    day::<probably_a_fire_hazard::Day06>(DefaultInput::File("06/input")),
+    day::<some_assembly_required::Day07>(DefaultInput::File("07/input")),
+    day::<matchsticks::Day08>(DefaultInput::File("08/input")),
+    day::<all_in_a_single_night::Day09>(DefaultInput::File("09/input")),
+    day::<elves_look_elves_say::Day10>(DefaultInput::File("10/input")),
+    day::<corporate_policy::Day11>(DefaultInput::File("11/input")),
+    day::<jsabacus_framework_io::Day12>(DefaultInput::File("12/input")),
+    day::<knights_of_the_dinner_table::Day13>(DefaultInput::File("13/input")),
+    day::<reindeer_olympics::Day14>(DefaultInput::File("14/input")),
+    day::<science_for_hungry_people::Day15>(DefaultInput::File("15/input")),
+    day::<aunt_sue::Day16>(DefaultInput::File("16/input")),
+    day::<no_such_thing_as_too_much::Day17>(DefaultInput::File("17/input")),
+    day::<like_a_gif_for_your_yard::Day18>(DefaultInput::File("18/input")),
+    day::<medicine_for_rudolph::Day19>(DefaultInput::File("19/input")),
+    day::<infinite_elves_and_infinite_houses::Day20>(DefaultInput::File("20/input")),
+    day::<rpg_simulator_20xx::Day21>(DefaultInput::File("21/input")),
+    day::<wizard_simulator_20xx::Day22>(DefaultInput::File("22/input")),
+    day::<opening_the_turing_lock::Day23>(DefaultInput::File("23/input")),
+    day::<it_hangs_in_the_balance::Day24>(DefaultInput::File("24/input")),
+    day::<let_it_snow::Day25>(DefaultInput::File("25/input")),
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
+
+fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().expect("Runner crate must live inside the workspace")
+}
+
+// Parameter overrides picked up by `run`, `bench`, `summary` and `params` when the file exists
+pub fn default_config_path() -> PathBuf {
+    workspace_root().join("params.txt")
+}
+
+// Answers for the checked-in inputs, used by `record` and `verify`
+pub fn default_answers_path() -> PathBuf {
+    workspace_root().join("answers.txt")
+}
+
+pub const YEAR: u16 = 2015;
+
+// Inputs downloaded by `fetch`
+pub fn default_cache_dir() -> PathBuf {
+    workspace_root().join(".cache").join("inputs")
+}
+
+// The input checked into the day's directory, or the downloaded one when there is none
+pub fn default_source(day: &Day) -> InputSource {
+    match day.input {
+        DefaultInput::File(path) => {
+            let path = workspace_root().join(path);
+            let cached = aoc_fetch::cache_path(&default_cache_dir(), YEAR, day.number);
+            InputSource::File(if !path.exists() && cached.exists() { cached } else { path })
+        },
+        DefaultInput::Inline(s) => InputSource::Inline(s.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_are_ordered() {
+        for (i, d) in DAYS.iter().enumerate() {
+            assert_eq!(d.number as usize, i + 1);
+        }
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find(7).unwrap().name, "some_assembly_required");
+        assert!(find(0).is_none());
+        assert!(find(26).is_none());
+    }
+
+    #[test]
+    fn test_robust() {
+        for day in &DAYS {
+            (day.check_robust)(300);
+        }
+    }
+}
@@ -0,0 +1,88 @@
+// Minimal JSON object writer for the `--json` output, one object per line
+pub struct JsonObject {
+    fields: Vec<String>,
+}
+
+pub fn escape(s: &str) -> String {
+    let mut r = String::with_capacity(s.len() + 2);
+    r.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => r.push_str("\\\""),
+            '\\' => r.push_str("\\\\"),
+            '\n' => r.push_str("\\n"),
+            '\r' => r.push_str("\\r"),
+            '\t' => r.push_str("\\t"),
+            c if (c as u32) < 0x20 => r.push_str(&format!("\\u{:04x}", c as u32)),
+            c => r.push(c),
+        }
+    }
+    r.push('"');
+    r
+}
+
+impl Default for JsonObject {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl JsonObject {
+    pub fn new() -> Self {
+        JsonObject { fields: Vec::new() }
+    }
+
+    fn raw(mut self, key: &str, value: String) -> Self {
+        self.fields.push(format!("{}:{}", escape(key), value));
+        self
+    }
+
+    pub fn string(self, key: &str, value: &str) -> Self {
+        self.raw(key, escape(value))
+    }
+
+    pub fn optional_string(self, key: &str, value: Option<&str>) -> Self {
+        self.raw(key, value.map_or_else(|| "null".into(), escape))
+    }
+
+    pub fn number(self, key: &str, value: impl Into<u128>) -> Self {
+        self.raw(key, value.into().to_string())
+    }
+
+    pub fn integer(self, key: &str, value: i64) -> Self {
+        self.raw(key, value.to_string())
+    }
+
+    pub fn object(self, key: &str, value: JsonObject) -> Self {
+        self.raw(key, value.to_string())
+    }
+}
+
+impl std::fmt::Display for JsonObject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{{}}}", self.fields.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("abc"), r#""abc""#);
+        assert_eq!(escape("a\"b\\c\nd\u{1}"), r#""a\"b\\c\nd\u0001""#);
+    }
+
+    #[test]
+    fn test_object() {
+        let o = JsonObject::new()
+            .number("day", 7_u8)
+            .string("name", "some_assembly_required")
+            .optional_string("part2", None)
+            .integer("offset", -3)
+            .object("timings_ns", JsonObject::new().number("parse", 12_u128));
+        assert_eq!(o.to_string(), r#"{"day":7,"name":"some_assembly_required","part2":null,"offset":-3,"timings_ns":{"parse":12}}"#);
+        assert_eq!(JsonObject::new().to_string(), "{}");
+    }
+}
@@ -0,0 +1,497 @@
+mod answers;
+mod cli;
+mod config;
+mod days;
+mod json;
+mod parallel;
+mod report;
+mod serve;
+
+use answers::AnswerFile;
+use aoc_common::trace::{with_tracer, Event};
+use aoc_common::visual::with_viewer;
+use aoc_common::{format_duration, with_budget, Budget, InputSource, Params, Rng, Solved, Stats};
+use cli::{Command, Overrides, ReportFormat, Selection, TraceFormat};
+use config::Config;
+use days::Day;
+use json::JsonObject;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+fn main() {
+    let command = match cli::parse_args(std::env::args().skip(1)) {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, cli::USAGE);
+            std::process::exit(2);
+        }
+    };
+    match command {
+        Command::Help => println!("{}", cli::USAGE),
+        Command::Run { selection, input, json, trace, visualize, timeout, overrides } => {
+            let config = load_config_or_exit(&overrides);
+            if !run(selection, input, json, trace, visualize, timeout, &config) {
+                std::process::exit(1);
+            }
+        },
+        Command::Bench { selection, input, runs, overrides } => {
+            let config = load_config_or_exit(&overrides);
+            if !bench(selection, input, runs, &config) {
+                std::process::exit(1);
+            }
+        },
+        Command::Generate { day, seed, size } => {
+            let day = days::find(day).expect("Day was validated by the argument parser");
+            print!("{}", (day.generate)(&mut Rng::new(seed), size.unwrap_or(day.default_size)));
+        },
+        Command::Record { selection, input, answers } => {
+            if !record(selection, input, &answers.unwrap_or_else(days::default_answers_path)) {
+                std::process::exit(1);
+            }
+        },
+        Command::Verify { selection, input, answers } => {
+            if !verify(selection, input, &answers.unwrap_or_else(days::default_answers_path)) {
+                std::process::exit(1);
+            }
+        },
+        Command::Serve { port, timeout, overrides } => {
+            let config = load_config_or_exit(&overrides);
+            if !serve(port, timeout, config) {
+                std::process::exit(1);
+            }
+        },
+        Command::Fetch { selection, base_url, cache_dir, session_file } => {
+            if !fetch(selection, base_url, cache_dir, session_file) {
+                std::process::exit(1);
+            }
+        },
+        Command::Summary { selection, input, threads, overrides } => {
+            let config = load_config_or_exit(&overrides);
+            if !summary(selection, input, threads.unwrap_or_else(parallel::default_threads), &config) {
+                std::process::exit(1);
+            }
+        },
+        Command::Report { selection, input, format, output, threads, overrides } => {
+            let config = load_config_or_exit(&overrides);
+            let threads = threads.unwrap_or_else(parallel::default_threads);
+            if !report(selection, input, format, output.as_deref(), threads, &config) {
+                std::process::exit(1);
+            }
+        },
+        Command::Example { selection } => {
+            if !examples(selection) {
+                std::process::exit(1);
+            }
+        },
+        Command::Params { selection, overrides } => {
+            let config = load_config_or_exit(&overrides);
+            for (day, _) in selected_days(selection, None) {
+                print_params(day, config.params(day));
+            }
+        },
+    }
+}
+
+// The default config file is optional, an explicit one has to exist
+fn load_config(overrides: &Overrides) -> Result<Config, String> {
+    let default_path = days::default_config_path();
+    let mut config = match &overrides.config {
+        Some(path) => Config::load(path)?,
+        None if default_path.exists() => Config::load(&default_path)?,
+        None => Config::new(),
+    };
+    for setting in &overrides.sets {
+        config.set(setting).map_err(|e| format!("Invalid setting '{}': {}", setting, e.message))?;
+    }
+    Ok(config)
+}
+
+fn load_config_or_exit(overrides: &Overrides) -> Config {
+    load_config(overrides).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(2);
+    })
+}
+
+fn print_params(day: &Day, params: &Params) {
+    for (param, value) in params.iter() {
+        let changed = if value != param.default { format!(" (default {})", param.default) } else { String::new() };
+        println!("{:02}.{} = {}{}  # {}", day.number, param.name, value, changed, param.description);
+    }
+}
+
+// Parameters that differ from their defaults, as `name = value`
+fn changed_params(params: &Params) -> Vec<String> {
+    params.iter()
+        .filter(|(param, value)| *value != param.default)
+        .map(|(param, value)| format!("{} = {}", param.name, value))
+        .collect()
+}
+
+fn selected_days(selection: Selection, input: Option<InputSource>) -> Vec<(&'static Day, InputSource)> {
+    match selection {
+        Selection::Day(n) => {
+            let day = days::find(n).expect("Day was validated by the argument parser");
+            let source = input.unwrap_or_else(|| days::default_source(day));
+            vec![(day, source)]
+        },
+        Selection::All => days::DAYS.iter().map(|d| (d, days::default_source(d))).collect(),
+    }
+}
+
+fn read_input(day: &Day, source: &InputSource) -> Result<String, String> {
+    source.read().map_err(|e| format!("Error reading {} for day {:02}: {}", source, day.number, e))
+}
+
+fn solve_day(day: &Day, source: &InputSource, params: &Params) -> Result<Solved, String> {
+    let input = read_input(day, source)?;
+    (day.solve)(&input, params).map_err(|e| format!("Error parsing input for day {:02}: {}", day.number, source.attach_to(e)))
+}
+
+fn run(selection: Selection, input: Option<InputSource>, json: bool, trace: Option<TraceFormat>, visualize: Option<u32>,
+       timeout: Option<Duration>, config: &Config) -> bool {
+    let mut success = true;
+    for (day, source) in selected_days(selection, input) {
+        let params = config.params(day);
+        let solve = || if json { run_day_json(day, &source, params) } else { run_day(day, &source, params) };
+        // Every day gets the full time
+        let run = || match timeout {
+            Some(timeout) => with_budget(Budget::new().with_timeout(timeout), solve),
+            None => solve(),
+        };
+        success &= match (trace, visualize) {
+            (Some(format), _) => with_tracer(print_event(day.number, format), run),
+            (None, Some(fps)) => {
+                // The answers are printed below the last frame
+                let mut renderer = aoc_render::Renderer::stdout(fps);
+                with_viewer(move |frame| { let _ = renderer.draw(frame); }, run)
+            },
+            (None, None) => run(),
+        };
+    }
+    success
+}
+
+// Events are printed as they happen, before the answers of the day
+fn print_event(day: u8, format: TraceFormat) -> impl FnMut(&Event) {
+    move |event| match format {
+        TraceFormat::Text => println!("  {}", event),
+        TraceFormat::Json => {
+            let line = event.fields.iter().fold(
+                JsonObject::new().number("day", day).string("event", event.kind),
+                |line, (key, value)| line.string(key, value));
+            println!("{}", line);
+        },
+    }
+}
+
+fn run_day(day: &Day, source: &InputSource, params: &Params) -> bool {
+    println!("Day {:02}: {}", day.number, day.name);
+    let changed = changed_params(params);
+    if !changed.is_empty() {
+        println!("With {}", changed.join(", "));
+    }
+    let (answer1, answer2) = match solve_day(day, source, params) {
+        Ok(solved) => solved.answers,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+    println!("Answer #1 is {}", answer1);
+    if let Some(answer2) = answer2 {
+        println!("Answer #2 is {}", answer2);
+    }
+    true
+}
+
+// One JSON object per line, failures are reported in an "error" field instead of the answers
+fn run_day_json(day: &Day, source: &InputSource, params: &Params) -> bool {
+    let line = JsonObject::new()
+        .number("day", day.number)
+        .string("name", day.name)
+        .string("input", &source.to_string());
+    let values = params.iter().fold(JsonObject::new(), |object, (param, value)| object.integer(param.name, value));
+    let line = line.object("params", values);
+    let (line, success) = match solve_day(day, source, params) {
+        Ok(Solved { answers: (answer1, answer2), parse_time, part1_time, part2_time }) => {
+            let timings = JsonObject::new()
+                .number("parse", parse_time.as_nanos())
+                .number("part1", part1_time.as_nanos())
+                .number("part2", part2_time.as_nanos());
+            let line = line
+                .string("part1", &answer1)
+                .optional_string("part2", answer2.as_deref())
+                .object("timings_ns", timings);
+            (line, true)
+        },
+        Err(e) => (line.string("error", &e), false),
+    };
+    println!("{}", line);
+    success
+}
+
+fn bench(selection: Selection, input: Option<InputSource>, runs: usize, config: &Config) -> bool {
+    if cfg!(debug_assertions) {
+        eprintln!("Warning: benchmarking a debug build, use --release for meaningful timings");
+    }
+    println!("{:>3}  {:<38}  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}",
+             "Day", "Name", "parse min", "median", "part1 min", "median", "part2 min", "median");
+    let mut success = true;
+    for (day, source) in selected_days(selection, input) {
+        success &= bench_day(day, &source, config.params(day), runs);
+    }
+    success
+}
+
+fn bench_day(day: &Day, source: &InputSource, params: &Params, runs: usize) -> bool {
+    let input = match read_input(day, source) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+    let timings = match (day.bench)(&input, params, runs) {
+        Ok(timings) => timings,
+        Err(e) => {
+            eprintln!("Error parsing input for day {:02}: {}", day.number, source.attach_to(e));
+            return false;
+        }
+    };
+    let cells = |s: Stats| format!("{:>10}  {:>10}", format_duration(s.min), format_duration(s.median));
+    println!("{:>3}  {:<38}  {}  {}  {}", day.number, day.name, cells(timings.parse), cells(timings.part1), cells(timings.part2));
+    true
+}
+
+fn record(selection: Selection, input: Option<InputSource>, path: &Path) -> bool {
+    let mut answers = match AnswerFile::load(path) {
+        Ok(a) => a,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+    // Recorded answers always belong to the default parameters
+    let config = Config::new();
+    let mut success = true;
+    for (day, source) in selected_days(selection, input) {
+        match solve_day(day, &source, config.params(day)) {
+            Ok(solved) => {
+                answers.record(day.number, &solved.answers);
+                println!("Day {:02}: recorded", day.number);
+            },
+            Err(e) => {
+                eprintln!("{}", e);
+                success = false;
+            },
+        }
+    }
+    if let Err(e) = answers.save(path) {
+        eprintln!("Error writing {}: {}", path.display(), e);
+        return false;
+    }
+    success
+}
+
+fn verify(selection: Selection, input: Option<InputSource>, path: &Path) -> bool {
+    let answers = match AnswerFile::load(path) {
+        Ok(a) => a,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+    let config = Config::new();
+    let mut failed_days = 0;
+    for (day, source) in selected_days(selection, input) {
+        let solved = match solve_day(day, &source, config.params(day)) {
+            Ok(solved) => solved,
+            Err(e) => {
+                eprintln!("{}", e);
+                failed_days += 1;
+                continue;
+            },
+        };
+        let mismatches = answers.verify(day.number, &solved.answers);
+        if mismatches.is_empty() {
+            println!("Day {:02}: ok", day.number);
+        } else {
+            failed_days += 1;
+            for m in mismatches {
+                println!("{}", m);
+            }
+        }
+    }
+    if failed_days > 0 {
+        println!("{} day(s) failed verification", failed_days);
+    }
+    failed_days == 0
+}
+
+// Runs the days on the samples from the puzzle descriptions, days without samples are skipped unless asked
+// for explicitly
+fn examples(selection: Selection) -> bool {
+    let single = matches!(selection, Selection::Day(_));
+    let (mut total, mut failed) = (0, 0);
+    for (day, _) in selected_days(selection, None) {
+        if day.examples.is_empty() {
+            if single {
+                println!("Day {:02}: no examples", day.number);
+            }
+            continue;
+        }
+        println!("Day {:02}: {}", day.number, day.name);
+        for (i, example) in day.examples.iter().enumerate() {
+            total += 1;
+            let params: Vec<_> = example.params.iter().map(|(name, value)| format!("{} = {}", name, value)).collect();
+            if params.is_empty() {
+                println!("  Example {}", i + 1);
+            } else {
+                println!("  Example {} with {}", i + 1, params.join(", "));
+            }
+            let (answer1, answer2) = match (day.solve_example)(example) {
+                Ok(answers) => answers,
+                Err(e) => {
+                    failed += 1;
+                    println!("    Error parsing the example: {}", e);
+                    continue;
+                },
+            };
+            let mut ok = true;
+            for (part, expected, actual) in [(1, example.part1, answer1), (2, example.part2, answer2)] {
+                let Some(expected) = expected else { continue };
+                let actual = actual.unwrap_or_else(|| "nothing".into());
+                let verdict = if actual == expected { "ok" } else { "MISMATCH" };
+                ok &= actual == expected;
+                println!("    Part {}: expected {}, got {}  {}", part, expected, actual, verdict);
+            }
+            if !ok {
+                failed += 1;
+            }
+        }
+    }
+    println!("{} example(s), {} failed", total, failed);
+    failed == 0
+}
+
+// Solves the days on a pool of threads, errors and panics are listed after the table
+fn summary(selection: Selection, input: Option<InputSource>, threads: usize, config: &Config) -> bool {
+    let days = selected_days(selection, input);
+    let start = Instant::now();
+    let results = parallel::run_parallel(&days, threads, |(day, source)| solve_day(day, source, config.params(day)));
+    let wall_time = start.elapsed();
+
+    println!("{:>3}  {:<38}  {:<16}  {:<16}  {:>10}", "Day", "Name", "Part 1", "Part 2", "Time");
+    let mut total = Duration::ZERO;
+    let mut errors = Vec::new();
+    for ((day, _), result) in days.iter().zip(results) {
+        let columns = match result {
+            Ok(Ok(Solved { answers: (answer1, answer2), parse_time, part1_time, part2_time })) => {
+                let time = parse_time + part1_time + part2_time;
+                total += time;
+                format!("{:<16}  {:<16}  {:>10}", answer1, answer2.unwrap_or_default(), format_duration(time))
+            },
+            Ok(Err(e)) => {
+                errors.push(e);
+                "failed".into()
+            },
+            Err(message) => {
+                errors.push(format!("Day {:02} panicked: {}", day.number, message));
+                "panicked".into()
+            },
+        };
+        println!("{:>3}  {:<38}  {}", day.number, day.name, columns);
+    }
+    println!("{} day(s) in {} on {} thread(s), {} summed over days",
+             days.len(), format_duration(wall_time), threads, format_duration(total));
+    for e in &errors {
+        eprintln!("{}", e);
+    }
+    errors.is_empty()
+}
+
+fn report_day(day: &Day, source: &InputSource, params: &Params) -> Result<report::DayReport, String> {
+    let input = read_input(day, source)?;
+    let parse_error = |e| format!("Error parsing input for day {:02}: {}", day.number, source.attach_to(e));
+    let solved = (day.solve)(&input, params).map_err(parse_error)?;
+    let picture = (day.picture)(&input, params).map_err(parse_error)?;
+    Ok(report::DayReport { input_bytes: input.len(), input_lines: input.lines().count(), solved, picture })
+}
+
+// Like `summary`, but writes a document, days that fail are listed in it and make the command fail
+fn report(selection: Selection, input: Option<InputSource>, format: ReportFormat, output: Option<&Path>, threads: usize,
+          config: &Config) -> bool {
+    let days = selected_days(selection, input);
+    let results = parallel::run_parallel(&days, threads, |(day, source)| report_day(day, source, config.params(day)));
+    let entries: Vec<_> = days.iter().zip(results)
+        .map(|((day, _), result)| report::Entry {
+            number: day.number,
+            name: day.name,
+            report: result.unwrap_or_else(|message| Err(format!("Day {:02} panicked: {}", day.number, message))),
+        })
+        .collect();
+    let document = report::render(&entries, format);
+    let written = match output {
+        Some(path) => std::fs::write(path, document)
+            .map(|_| println!("Wrote report of {} day(s) to {}", entries.len(), path.display()))
+            .map_err(|e| format!("Error writing {}: {}", path.display(), e)),
+        None => {
+            print!("{}", document);
+            Ok(())
+        },
+    };
+    if let Err(e) = written {
+        eprintln!("{}", e);
+        return false;
+    }
+    entries.iter().all(|e| e.report.is_ok())
+}
+
+// Only returns when the port can not be used, the address line tells clients where to connect
+fn serve(port: u16, timeout: Duration, config: Config) -> bool {
+    let server = match aoc_server::Server::bind(port).and_then(|s| Ok((s.local_addr()?, s))) {
+        Ok((addr, server)) => {
+            println!("Listening on http://{}", addr);
+            server
+        },
+        Err(e) => {
+            eprintln!("Error listening on port {}: {}", port, e);
+            return false;
+        }
+    };
+    server.serve(move |request| serve::handle(request, &config, timeout));
+    true
+}
+
+fn fetch(selection: Selection, base_url: Option<String>, cache_dir: Option<PathBuf>, session_file: Option<PathBuf>) -> bool {
+    let session = match session_file {
+        Some(path) => match std::fs::read_to_string(&path) {
+            Ok(s) => Some(s.trim().to_string()),
+            Err(e) => {
+                eprintln!("Error reading {}: {}", path.display(), e);
+                return false;
+            },
+        },
+        None => aoc_fetch::session_from_env(),
+    };
+    let base_url = base_url
+        .or_else(|| std::env::var("AOC_BASE_URL").ok())
+        .unwrap_or_else(|| aoc_fetch::DEFAULT_BASE_URL.into());
+    let mut fetcher = aoc_fetch::Fetcher::new(&base_url, cache_dir.unwrap_or_else(days::default_cache_dir));
+    if let Some(session) = session {
+        fetcher = fetcher.with_session(session);
+    }
+    let mut success = true;
+    for (day, _) in selected_days(selection, None) {
+        match fetcher.fetch(days::YEAR, day.number) {
+            Ok((path, true)) => println!("Day {:02}: downloaded to {}", day.number, path.display()),
+            Ok((path, false)) => println!("Day {:02}: cached at {}", day.number, path.display()),
+            Err(e) => {
+                eprintln!("Day {:02}: {}", day.number, e);
+                success = false;
+            },
+        }
+    }
+    success
+}
@@ -0,0 +1,73 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+// Runs `f` on every job using up to `threads` worker threads. Results come back in the order of `jobs`,
+// a job that panics gives Err with the panic message and does not affect the other jobs.
+pub fn run_parallel<T, R, F>(jobs: &[T], threads: usize, f: F) -> Vec<Result<R, String>>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<Result<R, String>>>> = Mutex::new(jobs.iter().map(|_| None).collect());
+    std::thread::scope(|s| {
+        for _ in 0..threads.clamp(1, jobs.len().max(1)) {
+            s.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(job) = jobs.get(i) else { break };
+                let result = catch_unwind(AssertUnwindSafe(|| f(job))).map_err(|payload| panic_message(payload.as_ref()));
+                results.lock().unwrap_or_else(|e| e.into_inner())[i] = Some(result);
+            });
+        }
+    });
+    results.into_inner()
+        .unwrap_or_else(|e| e.into_inner())
+        .into_iter()
+        .map(|r| r.expect("Every job was run"))
+        .collect()
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".into()
+    }
+}
+
+pub fn default_threads() -> usize {
+    std::thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_parallel() {
+        let jobs: Vec<u64> = (0..20).collect();
+        let results = run_parallel(&jobs, 4, |&n| n * n);
+        assert_eq!(results, jobs.iter().map(|n| Ok(n * n)).collect::<Vec<_>>());
+        assert_eq!(run_parallel(&jobs, 1, |&n| n).len(), 20);
+        assert_eq!(run_parallel(&[] as &[u64], 4, |&n| n), vec![]);
+    }
+
+    #[test]
+    fn test_panicking_job() {
+        let jobs = [1, 2, 3, 4];
+        let results = run_parallel(&jobs, 2, |&n| {
+            if n == 2 {
+                panic!("Job {} failed", n);
+            }
+            if n == 3 {
+                std::panic::panic_any(17);
+            }
+            n
+        });
+        assert_eq!(results, vec![Ok(1), Err("Job 2 failed".into()), Err("unknown panic".into()), Ok(4)]);
+    }
+}
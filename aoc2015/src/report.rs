@@ -0,0 +1,175 @@
+use crate::cli::ReportFormat;
+use aoc_common::{format_duration, Solved};
+use std::fmt::Write;
+use std::time::Duration;
+
+// What the report shows for a day that could be solved
+pub struct DayReport {
+    pub input_bytes: usize,
+    pub input_lines: usize,
+    pub solved: Solved,
+    pub picture: Option<String>,
+}
+
+pub struct Entry {
+    pub number: u8,
+    pub name: &'static str,
+    pub report: Result<DayReport, String>,
+}
+
+const HEADERS: [&str; 8] = ["Day", "Crate", "Part 1", "Part 2", "Parse", "Part 1 time", "Part 2 time", "Input"];
+
+// Table cells of a day, failed days keep the error for the section below the table
+fn cells(entry: &Entry) -> [String; 8] {
+    let (number, name) = (format!("{:02}", entry.number), entry.name.to_string());
+    match &entry.report {
+        Ok(DayReport { input_bytes, input_lines, solved, .. }) => {
+            let Solved { answers: (answer1, answer2), parse_time, part1_time, part2_time } = solved;
+            [
+                number, name, answer1.clone(), answer2.clone().unwrap_or_default(),
+                format_duration(*parse_time), format_duration(*part1_time), format_duration(*part2_time),
+                format!("{} bytes, {} lines", input_bytes, input_lines),
+            ]
+        },
+        Err(_) => [number, name, "failed".into(), String::new(), String::new(), String::new(), String::new(), String::new()],
+    }
+}
+
+fn total_time(entries: &[Entry]) -> Duration {
+    entries.iter()
+        .filter_map(|e| e.report.as_ref().ok())
+        .map(|r| r.solved.parse_time + r.solved.part1_time + r.solved.part2_time)
+        .sum()
+}
+
+pub fn render(entries: &[Entry], format: ReportFormat) -> String {
+    match format {
+        ReportFormat::Markdown => markdown(entries),
+        ReportFormat::Html => html(entries),
+    }
+}
+
+fn markdown_cell(s: &str) -> String {
+    s.replace('|', "\\|")
+}
+
+fn markdown(entries: &[Entry]) -> String {
+    let mut r = String::from("# Advent of Code 2015\n\n");
+    r += &format!("| {} |\n", HEADERS.join(" | "));
+    r += "|---:|---|---|---|---:|---:|---:|---:|\n";
+    for entry in entries {
+        let mut cells = cells(entry).map(|c| markdown_cell(&c));
+        cells[1] = format!("`{}`", cells[1]);
+        r += &format!("| {} |\n", cells.join(" | "));
+    }
+    r += &format!("\n{} day(s), {} in total\n", entries.len(), format_duration(total_time(entries)));
+    for entry in entries {
+        match &entry.report {
+            Ok(DayReport { picture: Some(picture), .. }) => {
+                r += &format!("\n## Day {:02}: {}\n\n```text\n{}", entry.number, entry.name, picture);
+                if !picture.ends_with('\n') {
+                    r.push('\n');
+                }
+                r += "```\n";
+            },
+            Ok(_) => {},
+            Err(e) => r += &format!("\n## Day {:02}: {}\n\n{}\n", entry.number, entry.name, e),
+        }
+    }
+    r
+}
+
+pub fn escape_html(s: &str) -> String {
+    let mut r = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => r.push_str("&amp;"),
+            '<' => r.push_str("&lt;"),
+            '>' => r.push_str("&gt;"),
+            '"' => r.push_str("&quot;"),
+            c => r.push(c),
+        }
+    }
+    r
+}
+
+fn html(entries: &[Entry]) -> String {
+    let mut r = String::from("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Advent of Code 2015</title>\n</head>\n<body>\n");
+    r += "<h1>Advent of Code 2015</h1>\n<table>\n<tr>";
+    for header in HEADERS {
+        let _ = write!(r, "<th>{}</th>", header);
+    }
+    r += "</tr>\n";
+    for entry in entries {
+        r += "<tr>";
+        for (i, cell) in cells(entry).iter().enumerate() {
+            let cell = escape_html(cell);
+            let _ = if i == 1 { write!(r, "<td><code>{}</code></td>", cell) } else { write!(r, "<td>{}</td>", cell) };
+        }
+        r += "</tr>\n";
+    }
+    let _ = writeln!(r, "</table>\n<p>{} day(s), {} in total</p>", entries.len(), format_duration(total_time(entries)));
+    for entry in entries {
+        let heading = format!("<h2>Day {:02}: {}</h2>", entry.number, escape_html(entry.name));
+        match &entry.report {
+            Ok(DayReport { picture: Some(picture), .. }) => {
+                let _ = writeln!(r, "{}\n<pre>{}</pre>", heading, escape_html(picture));
+            },
+            Ok(_) => {},
+            Err(e) => {
+                let _ = writeln!(r, "{}\n<p>{}</p>", heading, escape_html(e));
+            },
+        }
+    }
+    r += "</body>\n</html>\n";
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entries() -> Vec<Entry> {
+        let solved = |answer1: &str, answer2: Option<&str>| Solved {
+            answers: (answer1.into(), answer2.map(String::from)),
+            parse_time: Duration::from_micros(5),
+            part1_time: Duration::from_millis(2),
+            part2_time: Duration::from_millis(3),
+        };
+        vec![
+            Entry { number: 11, name: "corporate_policy", report: Ok(DayReport {
+                input_bytes: 9, input_lines: 1, solved: solved("ab|cd", Some("efgh")), picture: None,
+            }) },
+            Entry { number: 18, name: "like_a_gif_for_your_yard", report: Ok(DayReport {
+                input_bytes: 12, input_lines: 2, solved: solved("4", Some("7")), picture: Some("#.\n<#\n".into()),
+            }) },
+            Entry { number: 25, name: "let_it_snow", report: Err("Error parsing input for day 25: broken".into()) },
+        ]
+    }
+
+    #[test]
+    fn test_markdown() {
+        let report = render(&entries(), ReportFormat::Markdown);
+        assert!(report.starts_with("# Advent of Code 2015\n\n| Day | Crate | Part 1 |"), "{}", report);
+        assert!(report.contains("\n| 11 | `corporate_policy` | ab\\|cd | efgh | 5.0µs | 2.00ms | 3.00ms | 9 bytes, 1 lines |\n"), "{}", report);
+        assert!(report.contains("\n| 25 | `let_it_snow` | failed |  |  |  |  |  |\n"), "{}", report);
+        assert!(report.contains("\n3 day(s), 10.01ms in total\n"), "{}", report);
+        assert!(report.contains("\n## Day 18: like_a_gif_for_your_yard\n\n```text\n#.\n<#\n```\n"), "{}", report);
+        assert!(report.ends_with("\n## Day 25: let_it_snow\n\nError parsing input for day 25: broken\n"), "{}", report);
+    }
+
+    #[test]
+    fn test_html() {
+        let report = render(&entries(), ReportFormat::Html);
+        assert!(report.starts_with("<!DOCTYPE html>\n"), "{}", report);
+        assert!(report.contains("<tr><td>11</td><td><code>corporate_policy</code></td><td>ab|cd</td><td>efgh</td>"), "{}", report);
+        assert!(report.contains("<h2>Day 18: like_a_gif_for_your_yard</h2>\n<pre>#.\n&lt;#\n</pre>\n"), "{}", report);
+        assert!(report.contains("<h2>Day 25: let_it_snow</h2>\n<p>Error parsing input for day 25: broken</p>\n"), "{}", report);
+        assert!(report.ends_with("</body>\n</html>\n"));
+    }
+
+    #[test]
+    fn test_escape_html() {
+        assert_eq!(escape_html(r#"<a href="x">&</a>"#), "&lt;a href=&quot;x&quot;&gt;&amp;&lt;/a&gt;");
+    }
+}
@@ -0,0 +1,166 @@
+use crate::config::Config;
+use crate::days::{self, Day};
+use crate::json::JsonObject;
+use aoc_common::{with_budget, Budget, Params, ParseError};
+use aoc_server::{Request, Response};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::time::Duration;
+
+// Solvers that do not check their budget can not be stopped, they keep their thread busy after the request
+// timed out. At most this many solves run at once so such threads can not pile up.
+const MAX_RUNNING: usize = 16;
+static RUNNING: AtomicUsize = AtomicUsize::new(0);
+
+// Time the searches get to stop by themselves after the budget ran out
+const GRACE: Duration = Duration::from_secs(1);
+
+fn error(status: u16, message: &str) -> Response {
+    Response::json(status, JsonObject::new().string("error", message))
+}
+
+// `/day/<DAY>/part/<PART>`, days with or without a leading zero
+fn route(path: &str) -> Option<(&'static Day, u8)> {
+    let mut segments = path.strip_prefix('/')?.split('/');
+    let (Some("day"), Some(day), Some("part"), Some(part), None) =
+        (segments.next(), segments.next(), segments.next(), segments.next(), segments.next()) else {
+        return None;
+    };
+    let day = days::find(day.parse().ok()?)?;
+    let part = part.parse().ok().filter(|p| (1..=2).contains(p))?;
+    Some((day, part))
+}
+
+enum Solve {
+    Done(Result<Option<String>, ParseError>),
+    TimedOut,
+    Busy,
+    Panicked,
+}
+
+// Solves on a thread of its own with a budget of `timeout`
+fn solve_within(day: &'static Day, input: String, params: Params, part: u8, timeout: Duration) -> Solve {
+    struct Running;
+
+    impl Drop for Running {
+        fn drop(&mut self) {
+            RUNNING.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+
+    if RUNNING.fetch_add(1, Ordering::Relaxed) >= MAX_RUNNING {
+        drop(Running);
+        return Solve::Busy;
+    }
+    let running = Running;
+    let budget = Budget::new().with_timeout(timeout);
+    let (sender, receiver) = mpsc::channel();
+    let worker_budget = budget.clone();
+    std::thread::spawn(move || {
+        let _running = running;
+        let result = with_budget(worker_budget.clone(), || (day.solve_part)(&input, &params, part));
+        let _ = sender.send((result, worker_budget.is_exhausted()));
+    });
+    let solve = match receiver.recv_timeout(timeout.saturating_add(GRACE)) {
+        Ok((_, true)) | Err(mpsc::RecvTimeoutError::Timeout) => Solve::TimedOut,
+        Ok((result, false)) => Solve::Done(result),
+        Err(mpsc::RecvTimeoutError::Disconnected) => Solve::Panicked,
+    };
+    budget.cancel();
+    solve
+}
+
+// Solves one part of a day for the input in the body, with the parameters of the config changed by
+// the query string. Solves that take longer than `timeout` are answered with a 503.
+pub fn handle(request: &Request, config: &Config, timeout: Duration) -> Response {
+    let Some((day, part)) = route(&request.path) else {
+        return error(404, &format!("No such endpoint '{}', expected /day/<DAY>/part/<PART>", request.path));
+    };
+    if request.method != "POST" {
+        return error(405, &format!("Expected POST with the input as body, not {}", request.method));
+    }
+    let mut params = config.params(day).clone();
+    for (name, value) in &request.query {
+        let Ok(value) = value.parse() else {
+            return error(400, &format!("Invalid value '{}' for parameter '{}'", value, name));
+        };
+        if let Err(e) = params.set(name, value) {
+            return error(400, &e);
+        }
+    }
+    match solve_within(day, request.body.clone(), params, part, timeout) {
+        Solve::Done(Ok(Some(answer))) => {
+            let body = JsonObject::new().number("day", day.number).number("part", part).string("answer", &answer);
+            Response::json(200, body)
+        },
+        Solve::Done(Ok(None)) => error(404, &format!("Day {:02} has no part {}", day.number, part)),
+        Solve::Done(Err(e)) => error(400, &format!("Error parsing input for day {:02}: {}", day.number, e)),
+        Solve::TimedOut => {
+            error(503, &format!("Day {:02} part {} took longer than {:?}", day.number, part, timeout))
+        },
+        Solve::Busy => error(503, "Too many solves are running, try again later"),
+        Solve::Panicked => error(500, &format!("Solver of day {:02} panicked", day.number)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn post(path: &str, query: &[(&str, &str)], body: &str) -> (u16, String) {
+        let query = query.iter().map(|(n, v)| (n.to_string(), v.to_string())).collect();
+        let request = Request { method: "POST".into(), path: path.into(), query, body: body.into() };
+        let Response { status, body } = handle(&request, &Config::new(), Duration::from_secs(60));
+        (status, body)
+    }
+
+    #[test]
+    fn test_handle() {
+        assert_eq!(post("/day/01/part/1", &[], "(()(()("), (200, r#"{"day":1,"part":1,"answer":"3"}"#.into()));
+        assert_eq!(post("/day/1/part/2", &[], "())"), (200, r#"{"day":1,"part":2,"answer":"3"}"#.into()));
+        let reindeer = "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.\n";
+        assert_eq!(post("/day/14/part/1", &[("seconds", "1000")], reindeer), (200, r#"{"day":14,"part":1,"answer":"1120"}"#.into()));
+    }
+
+    #[test]
+    fn test_handle_errors() {
+        let status = |path: &str, query: &[(&str, &str)], body: &str| post(path, query, body).0;
+        assert_eq!(status("/day/26/part/1", &[], ""), 404);
+        assert_eq!(status("/day/01/part/3", &[], ""), 404);
+        assert_eq!(status("/day/01/part/1/", &[], ""), 404);
+        assert_eq!(status("/days", &[], ""), 404);
+        assert_eq!(status("/day/01/part/1", &[], "(x"), 400);
+        assert_eq!(status("/day/14/part/1", &[("seconds", "many")], ""), 400);
+        assert_eq!(status("/day/14/part/1", &[("second", "1")], ""), 400);
+        assert_eq!(post("/day/25/part/2", &[], "row 1, column 1."), (404, r#"{"error":"Day 25 has no part 2"}"#.into()));
+
+        let request = Request { method: "GET".into(), path: "/day/01/part/1".into(), query: Vec::new(), body: String::new() };
+        assert_eq!(handle(&request, &Config::new(), Duration::from_secs(60)).status, 405);
+    }
+
+    #[test]
+    fn test_handle_timeout() {
+        let post = |path: &str, body: &str| {
+            let request = Request { method: "POST".into(), path: path.into(), query: Vec::new(), body: body.into() };
+            handle(&request, &Config::new(), Duration::from_millis(50))
+        };
+        // The program of day 23 never ends and the search of day 04 takes long, both stop when the budget runs out
+        let response = post("/day/23/part/1", "jmp +0\n");
+        assert_eq!((response.status, response.body.as_str()), (503, r#"{"error":"Day 23 part 1 took longer than 50ms"}"#));
+        assert_eq!(post("/day/04/part/2", "yzbqklnj").status, 503);
+        assert_eq!(post("/day/01/part/1", "(()").status, 200);
+    }
+
+    #[test]
+    fn test_timeout_frees_slot() {
+        let post = |path: &str, body: &str| {
+            let request = Request { method: "POST".into(), path: path.into(), query: Vec::new(), body: body.into() };
+            handle(&request, &Config::new(), Duration::from_millis(20)).status
+        };
+        // Every solve that timed out would keep its slot if its thread kept running
+        for _ in 0..MAX_RUNNING {
+            assert_eq!(post("/day/23/part/2", "jmp +0\n"), 503);
+        }
+        assert_eq!(post("/day/01/part/1", "(()"), 200);
+    }
+}
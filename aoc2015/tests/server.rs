@@ -0,0 +1,81 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::process::{Child, Command, Stdio};
+
+// Runs `aoc2015 serve` on a free port, stopped when dropped
+struct Server {
+    child: Child,
+    addr: SocketAddr,
+}
+
+impl Server {
+    fn start(args: &[&str]) -> Server {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_aoc2015"))
+            .args(["serve", "--port", "0"])
+            .args(args)
+            .stdout(Stdio::piped())
+            .spawn()
+            .expect("Runner starts");
+        let mut line = String::new();
+        BufReader::new(child.stdout.take().unwrap()).read_line(&mut line).unwrap();
+        let addr = line.trim().strip_prefix("Listening on http://").unwrap_or_else(|| panic!("Unexpected output '{}'", line));
+        Server { addr: addr.parse().unwrap(), child }
+    }
+
+    // Status and body of the response
+    fn request(&self, method: &str, target: &str, body: &str) -> (u16, String) {
+        let mut stream = TcpStream::connect(self.addr).unwrap();
+        // In one write, the server may close the connection as soon as it has seen a malformed request line
+        let request = format!("{} {} HTTP/1.1\r\nHost: {}\r\nContent-Length: {}\r\n\r\n{}", method, target, self.addr, body.len(), body);
+        stream.write_all(request.as_bytes()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").expect("Response has a head and a body");
+        assert!(head.contains("Content-Type: application/json"), "{}", head);
+        let status = head.split(' ').nth(1).and_then(|s| s.parse().ok()).expect("Response has a status");
+        (status, body.to_string())
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[test]
+fn test_solve() {
+    let server = Server::start(&[]);
+    assert!(server.addr.ip().is_loopback());
+    let circuit = "123 -> x\n456 -> y\nx AND y -> d\nx OR y -> e\nd OR e -> a\n";
+    assert_eq!(server.request("POST", "/day/07/part/1", circuit), (200, r#"{"day":7,"part":1,"answer":"507"}"#.into()));
+    assert_eq!(server.request("POST", "/day/7/part/2", circuit), (200, r#"{"day":7,"part":2,"answer":"507"}"#.into()));
+
+    let input = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/../01/input.txt")).unwrap();
+    let (status, body) = server.request("POST", "/day/01/part/1", &input);
+    assert_eq!(status, 200, "{}", body);
+    assert!(body.starts_with(r#"{"day":1,"part":1,"answer":""#), "{}", body);
+}
+
+#[test]
+fn test_params() {
+    let reindeer = "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.\n\
+                    Dancer can fly 16 km/s for 11 seconds, but then must rest for 162 seconds.\n";
+    let server = Server::start(&["--set", "14.seconds=1000"]);
+    assert_eq!(server.request("POST", "/day/14/part/1", reindeer), (200, r#"{"day":14,"part":1,"answer":"1120"}"#.into()));
+    assert_eq!(server.request("POST", "/day/14/part/2?seconds=1", reindeer), (200, r#"{"day":14,"part":2,"answer":"1"}"#.into()));
+    assert_eq!(server.request("POST", "/day/14/part/1?seconds=-1", reindeer).0, 400);
+}
+
+#[test]
+fn test_errors() {
+    let server = Server::start(&[]);
+    let (status, body) = server.request("POST", "/day/01/part/1", "(()x");
+    assert_eq!(status, 400);
+    assert!(body.starts_with(r#"{"error":"Error parsing input for day 01: "#), "{}", body);
+    assert_eq!(server.request("GET", "/day/01/part/1", "").0, 405);
+    assert_eq!(server.request("POST", "/day/26/part/1", "").0, 404);
+    assert_eq!(server.request("POST", "/day/25/part/2", "row 1, column 1.").0, 404);
+    assert_eq!(server.request("GET", "/day/01 /part/1", "").0, 400);
+}
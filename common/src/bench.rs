@@ -0,0 +1,116 @@
+use crate::{Answer, Answers, Params, ParseError, Solution};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        assert!(!samples.is_empty(), "Need at least one sample");
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+        Stats { min: sorted[0], median }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+// Every run parses the input again, so part 1 and part 2 never see state left over by a previous run
+pub fn bench<S: Solution>(input: &str, params: &Params, runs: usize) -> Result<Timings, ParseError> {
+    assert!(runs > 0, "Need at least one run");
+    let mut parse = Vec::with_capacity(runs);
+    let mut part1 = Vec::with_capacity(runs);
+    let mut part2 = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let start = Instant::now();
+        let parsed = S::parse_with(black_box(input), params)?;
+        parse.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part1(black_box(&parsed)));
+        part1.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part2(black_box(&parsed)));
+        part2.push(start.elapsed());
+    }
+    Ok(Timings {
+        parse: Stats::from_samples(&parse),
+        part1: Stats::from_samples(&part1),
+        part2: Stats::from_samples(&part2),
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solved {
+    pub answers: Answers,
+    pub parse_time: Duration,
+    pub part1_time: Duration,
+    pub part2_time: Duration,
+}
+
+// Single run of a day, keeping the answers alongside the timings
+pub fn solve_timed<S: Solution>(input: &str, params: &Params) -> Result<Solved, ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse_with(input, params)?;
+    let parse_time = start.elapsed();
+
+    let start = Instant::now();
+    let answer1 = S::part1(&parsed).to_answer().expect("Part 1 always has an answer");
+    let part1_time = start.elapsed();
+
+    let start = Instant::now();
+    let answer2 = S::part2(&parsed).to_answer();
+    let part2_time = start.elapsed();
+
+    Ok(Solved { answers: (answer1, answer2), parse_time, part1_time, part2_time })
+}
+
+pub fn format_duration(d: Duration) -> String {
+    let secs = d.as_secs_f64();
+    if secs >= 1.0 {
+        format!("{:.2}s", secs)
+    } else if secs >= 1e-3 {
+        format!("{:.2}ms", secs * 1e3)
+    } else {
+        format!("{:.1}µs", secs * 1e6)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn test_stats() {
+        assert_eq!(Stats::from_samples(&[ms(5)]), Stats { min: ms(5), median: ms(5) });
+        assert_eq!(Stats::from_samples(&[ms(9), ms(1), ms(4)]), Stats { min: ms(1), median: ms(4) });
+        assert_eq!(Stats::from_samples(&[ms(9), ms(1), ms(4), ms(2)]), Stats { min: ms(1), median: ms(3) });
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(1500)), "1.5µs");
+        assert_eq!(format_duration(Duration::from_micros(12345)), "12.35ms");
+        assert_eq!(format_duration(Duration::from_millis(2500)), "2.50s");
+    }
+}
@@ -0,0 +1,141 @@
+use crate::scoped::with_scoped;
+use crate::Answer;
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+// Lets long searches stop early, either when `cancel` is called on any clone or when the time runs out.
+// Searches check it now and then, so they stop soon after but not at once.
+#[derive(Debug, Clone)]
+pub struct Budget {
+    cancelled: Arc<AtomicBool>,
+    deadline: Option<Instant>,
+}
+
+impl Default for Budget {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Budget {
+    // Runs until cancelled
+    pub fn new() -> Budget {
+        Budget { cancelled: Arc::new(AtomicBool::new(false)), deadline: None }
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Budget {
+        self.deadline = Instant::now().checked_add(timeout);
+        self
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_exhausted(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed) || self.deadline.is_some_and(|d| Instant::now() >= d)
+    }
+
+    // The budget installed by `with_budget` on this thread, solvers use it for their parts
+    pub fn current() -> Budget {
+        CURRENT.with(|c| c.borrow().clone()).unwrap_or_default()
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Budget>> = const { RefCell::new(None) };
+}
+
+// Runs `f` with `budget` as the current one, the previous budget is restored afterwards, also on panic
+pub fn with_budget<R>(budget: Budget, f: impl FnOnce() -> R) -> R {
+    with_scoped(&CURRENT, budget, f)
+}
+
+// Result of a search that can run out of budget
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome<T> {
+    Done(T),
+    // `partial` is the best answer found before stopping, if the search has one. What a step is depends on
+    // the search, e.g. a hash or a game played.
+    Stopped { partial: Option<T>, steps: u64 },
+}
+
+impl<T> Outcome<T> {
+    pub fn done(self) -> Option<T> {
+        match self {
+            Outcome::Done(v) => Some(v),
+            Outcome::Stopped { .. } => None,
+        }
+    }
+
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Outcome<U> {
+        match self {
+            Outcome::Done(v) => Outcome::Done(f(v)),
+            Outcome::Stopped { partial, steps } => Outcome::Stopped { partial: partial.map(f), steps },
+        }
+    }
+}
+
+// For the runners of the days, which print their answers directly
+impl<T: std::fmt::Display> std::fmt::Display for Outcome<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Done(v) => write!(f, "{}", v),
+            Outcome::Stopped { partial: Some(v), steps } => write!(f, "{} (best so far, stopped after {} steps)", v, steps),
+            Outcome::Stopped { partial: None, steps } => write!(f, "none (stopped after {} steps)", steps),
+        }
+    }
+}
+
+impl<T: Answer> Answer for Outcome<T> {
+    fn to_answer(&self) -> Option<String> {
+        match self {
+            Outcome::Done(v) => v.to_answer(),
+            &Outcome::Stopped { ref partial, steps } => {
+                let partial = partial.as_ref().and_then(Answer::to_answer);
+                Some(Outcome::Stopped { partial, steps }.to_string())
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_budget() {
+        let budget = Budget::new();
+        assert!(!budget.is_exhausted());
+        budget.clone().cancel();
+        assert!(budget.is_exhausted());
+
+        assert!(Budget::new().with_timeout(Duration::ZERO).is_exhausted());
+        assert!(!Budget::new().with_timeout(Duration::from_secs(60)).is_exhausted());
+        assert!(!Budget::new().with_timeout(Duration::MAX).is_exhausted());
+    }
+
+    #[test]
+    fn test_with_budget() {
+        assert!(!Budget::current().is_exhausted());
+        let budget = Budget::new();
+        budget.cancel();
+        assert!(with_budget(budget, || Budget::current().is_exhausted()));
+        assert!(!Budget::current().is_exhausted());
+    }
+
+    #[test]
+    fn test_outcome() {
+        assert_eq!(Outcome::Done(3).map(|v| v * 2), Outcome::Done(6));
+        assert_eq!(Outcome::Stopped { partial: Some(3), steps: 7 }.map(|v| v * 2), Outcome::Stopped { partial: Some(6), steps: 7 });
+        assert_eq!(Outcome::Done(3).done(), Some(3));
+        assert_eq!(Outcome::<i32>::Stopped { partial: None, steps: 7 }.done(), None);
+
+        assert_eq!(Outcome::Done(42).to_answer(), Some("42".into()));
+        assert_eq!(Outcome::Stopped { partial: Some(42), steps: 7 }.to_answer(), Some("42 (best so far, stopped after 7 steps)".into()));
+        assert_eq!(Outcome::<i32>::Stopped { partial: None, steps: 7 }.to_answer(), Some("none (stopped after 7 steps)".into()));
+        assert_eq!(Outcome::Stopped { partial: Some(42), steps: 7 }.to_string(), "42 (best so far, stopped after 7 steps)");
+    }
+}
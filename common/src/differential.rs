@@ -0,0 +1,55 @@
+use crate::Rng;
+use std::fmt::Debug;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+// For the tests of a day: a fast implementation has to agree with a naive reference that is easy to
+// trust. Inputs come from `generate`, the first disagreement or panic is reported with its seed and input.
+pub fn check_against_reference<I, O, G, F, R>(runs: u64, generate: G, fast: F, reference: R)
+where
+    I: Debug,
+    O: PartialEq + Debug,
+    G: Fn(&mut Rng) -> I,
+    F: Fn(&I) -> O,
+    R: Fn(&I) -> O,
+{
+    for seed in 0..runs {
+        let input = generate(&mut Rng::new(seed));
+        let Ok(actual) = catch_unwind(AssertUnwindSafe(|| fast(&input))) else {
+            panic!("Seed {} panics for {:?}", seed, input);
+        };
+        let expected = reference(&input);
+        if actual != expected {
+            panic!("Seed {} gives {:?} instead of {:?} for {:?}", seed, actual, expected, input);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn triangle(n: &u64) -> u64 {
+        n * (n + 1) / 2
+    }
+
+    fn naive_triangle(n: &u64) -> u64 {
+        (1..=*n).sum()
+    }
+
+    #[test]
+    fn test_agreement() {
+        check_against_reference(100, |rng| rng.range(0..=1000) as u64, triangle, naive_triangle);
+    }
+
+    #[test]
+    #[should_panic(expected = "instead of")]
+    fn test_disagreement() {
+        check_against_reference(100, |rng| rng.range(0..=1000) as u64, |n| triangle(n) + n / 500, naive_triangle);
+    }
+
+    #[test]
+    #[should_panic(expected = "panics for")]
+    fn test_panic() {
+        check_against_reference(100, |rng| rng.range(0..=1000) as u64, |&n| if n > 900 { panic!("Too large") } else { triangle(&n) }, naive_triangle);
+    }
+}
@@ -0,0 +1,137 @@
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<PathBuf>,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+// Number of characters of the offending line shown on either side of the error column
+const CONTEXT_WIDTH: usize = 40;
+
+fn char_column(text: &str, byte_offset: usize) -> usize {
+    text[..byte_offset].chars().count() + 1
+}
+
+impl ParseError {
+    // Error inside `part`, which has to be a subslice of the single line `text`.
+    // Line numbers are filled in by `parse_lines` or `on_line`.
+    pub fn new(text: &str, part: &str, message: impl Into<String>) -> Self {
+        let text_start = text.as_ptr() as usize;
+        let part_start = part.as_ptr() as usize;
+        let column = if part_start >= text_start && part_start <= text_start + text.len() {
+            char_column(text, part_start - text_start)
+        } else {
+            1
+        };
+        ParseError { file: None, line: 1, column, text: text.into(), message: message.into() }
+    }
+
+    // Error at a byte offset into a (possibly multi-line) input
+    pub fn at_offset(input: &str, offset: usize, message: impl Into<String>) -> Self {
+        let offset = offset.min(input.len());
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..].find('\n').map_or(input.len(), |i| offset + i);
+        let text = &input[line_start..line_end];
+        ParseError {
+            file: None,
+            line: input[..line_start].matches('\n').count() + 1,
+            column: char_column(text, offset - line_start),
+            text: text.into(),
+            message: message.into(),
+        }
+    }
+
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+
+    pub fn with_file(mut self, file: impl Into<PathBuf>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}:", file.display())?,
+            None => write!(f, "<input>:")?,
+        }
+        writeln!(f, "{}:{}: {}", self.line, self.column, self.message)?;
+        let skip = self.column.saturating_sub(CONTEXT_WIDTH + 1);
+        let shown: String = self.text.chars().skip(skip).take(2 * CONTEXT_WIDTH).collect();
+        let ellipsis = if skip > 0 { "..." } else { "" };
+        writeln!(f, "    {}{}", ellipsis, shown)?;
+        write!(f, "    {}{}^", " ".repeat(ellipsis.len()), " ".repeat(self.column - 1 - skip))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub fn parse_value<T: std::str::FromStr>(text: &str, part: &str) -> Result<T, ParseError> {
+    part.parse::<T>().map_err(|_| ParseError::new(text, part, format!("Invalid value '{}'", part)))
+}
+
+pub fn parse_lines<T, F>(input: &str, mut parse_line: F) -> Result<Vec<T>, ParseError>
+    where F: FnMut(&str) -> Result<T, ParseError>
+{
+    input.lines()
+        .enumerate()
+        .map(|(i, l)| parse_line(l).map_err(|e| e.on_line(i + 1)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let line = "1x2xfoo";
+        let e = ParseError::new(line, &line[4..], "Invalid height");
+        assert_eq!(e.line, 1);
+        assert_eq!(e.column, 5);
+        assert_eq!(e.text, "1x2xfoo");
+        assert_eq!(ParseError::new(line, "unrelated", "").column, 1);
+    }
+
+    #[test]
+    fn test_at_offset() {
+        let input = "abc\ndef\nghi";
+        let e = ParseError::at_offset(input, 5, "Unexpected 'e'");
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 2, "def"));
+        let e = ParseError::at_offset(input, 0, "");
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 1, "abc"));
+        let e = ParseError::at_offset(input, 11, "Unexpected end of input");
+        assert_eq!((e.line, e.column, e.text.as_str()), (3, 4, "ghi"));
+    }
+
+    #[test]
+    fn test_display() {
+        let line = "1x2xfoo";
+        let e = ParseError::new(line, &line[4..], "Invalid value 'foo'").on_line(3).with_file("02/input");
+        assert_eq!(e.to_string(), "02/input:3:5: Invalid value 'foo'\n    1x2xfoo\n        ^");
+        let long = "a".repeat(100) + "!";
+        let e = ParseError::at_offset(&long, 100, "Unexpected '!'");
+        assert_eq!(e.to_string(), format!("<input>:1:101: Unexpected '!'\n    ...{}!\n    {}^", "a".repeat(40), " ".repeat(43)));
+    }
+
+    #[test]
+    fn test_parse_value() {
+        assert_eq!(parse_value::<i32>("x = 42", "42"), Ok(42));
+        let line = "x = 4a";
+        assert_eq!(parse_value::<i32>(line, &line[4..]).unwrap_err().column, 5);
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(parse_lines("1\n2\n3", |l| parse_value::<i32>(l, l)), Ok(vec![1, 2, 3]));
+        let e = parse_lines("1\n2\nthree", |l| parse_value::<i32>(l, l)).unwrap_err();
+        assert_eq!((e.line, e.column), (3, 1));
+    }
+}
@@ -0,0 +1,55 @@
+use crate::{Answer, Params, ParseError, Solution};
+
+// Sample input from a puzzle description with the answers given there. Parts without an answer are not
+// solved, some samples only make sense for one part or with different parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Example {
+    pub input: &'static str,
+    pub params: &'static [(&'static str, i64)],
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+}
+
+impl Example {
+    pub const fn new(input: &'static str) -> Example {
+        Example { input, params: &[], part1: None, part2: None }
+    }
+
+    pub const fn with_params(mut self, params: &'static [(&'static str, i64)]) -> Example {
+        self.params = params;
+        self
+    }
+
+    pub const fn part1(mut self, answer: &'static str) -> Example {
+        self.part1 = Some(answer);
+        self
+    }
+
+    pub const fn part2(mut self, answer: &'static str) -> Example {
+        self.part2 = Some(answer);
+        self
+    }
+}
+
+// Actual answers for the parts the example has answers for
+pub type ExampleAnswers = (Option<String>, Option<String>);
+
+pub fn solve_example<S: Solution>(example: &Example) -> Result<ExampleAnswers, ParseError> {
+    let mut params = Params::defaults(S::PARAMS);
+    for &(name, value) in example.params {
+        params.set(name, value).unwrap_or_else(|e| panic!("Invalid example for day {}: {}", S::DAY, e));
+    }
+    let parsed = S::parse_with(example.input, &params)?;
+    let answer1 = example.part1.and_then(|_| S::part1(&parsed).to_answer());
+    let answer2 = example.part2.and_then(|_| S::part2(&parsed).to_answer());
+    Ok((answer1, answer2))
+}
+
+// For the tests of a day, panics on the first example that does not give its answers
+pub fn check_examples<S: Solution>() {
+    for (i, example) in S::EXAMPLES.iter().enumerate() {
+        let actual = solve_example::<S>(example).unwrap_or_else(|e| panic!("Example {} does not parse: {}", i + 1, e));
+        let expected = (example.part1.map(String::from), example.part2.map(String::from));
+        assert_eq!(actual, expected, "Example {}", i + 1);
+    }
+}
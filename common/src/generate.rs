@@ -0,0 +1,185 @@
+use crate::{with_budget, Budget, Solution};
+use std::ops::RangeInclusive;
+use std::panic::AssertUnwindSafe;
+use std::time::Duration;
+
+// Small seeded generator (SplitMix64), so that a seed always reproduces the same input
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    // Uniform index in 0..len
+    pub fn index(&mut self, len: usize) -> usize {
+        assert!(len > 0, "Can not pick from an empty range");
+        ((self.next_u64() as u128 * len as u128) >> 64) as usize
+    }
+
+    pub fn range(&mut self, r: RangeInclusive<i64>) -> i64 {
+        let (lo, hi) = (*r.start(), *r.end());
+        assert!(lo <= hi, "Empty range");
+        let span = (hi - lo) as u64 as u128 + 1;
+        lo + ((self.next_u64() as u128 * span) >> 64) as i64
+    }
+
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+
+    pub fn string_from(&mut self, alphabet: &str, len: usize) -> String {
+        let chars: Vec<char> = alphabet.chars().collect();
+        (0..len).map(|_| *self.choose(&chars)).collect()
+    }
+}
+
+pub const LOWERCASE: &str = "abcdefghijklmnopqrstuvwxyz";
+
+// Distinct lowercase names in the order a, b, ..., z, aa, ab, ...
+pub fn lowercase_name(mut n: usize) -> String {
+    let mut r = Vec::new();
+    loop {
+        r.push(b'a' + (n % 26) as u8);
+        if n < 26 { break; }
+        n = n / 26 - 1;
+    }
+    r.reverse();
+    String::from_utf8(r).expect("Names are ASCII")
+}
+
+// Random puzzle inputs for stress testing, `size` is a day specific knob (lines, grid width, ...)
+pub trait Generator: Solution {
+    const DEFAULT_SIZE: usize;
+
+    fn generate(rng: &mut Rng, size: usize) -> String;
+}
+
+const PART_TIMEOUT: Duration = Duration::from_millis(2);
+
+// Bytes that are likely to mean something to a parser
+const SPECIAL_BYTES: &[u8] = b"\n \"\\,.:;-+=>[]{}0123456789x";
+
+// Arbitrary text with every byte equally likely, invalid UTF-8 is replaced
+pub fn random_text(rng: &mut Rng, max_len: usize) -> String {
+    let bytes: Vec<u8> = (0..rng.index(max_len + 1)).map(|_| rng.index(256) as u8).collect();
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+// A few random edits of `input`: bytes are replaced, deleted, inserted and short runs duplicated
+pub fn mutate(rng: &mut Rng, input: &str) -> String {
+    let mut bytes = input.as_bytes().to_vec();
+    for _ in 0..rng.range(1..=4) {
+        let i = rng.index(bytes.len() + 1);
+        match rng.index(4) {
+            0 if i < bytes.len() => bytes[i] = rng.index(256) as u8,
+            1 if i < bytes.len() => { bytes.remove(i); },
+            2 => bytes.insert(i, *rng.choose(SPECIAL_BYTES)),
+            _ => {
+                let run = bytes[i..(i + rng.index(8)).min(bytes.len())].to_vec();
+                bytes.splice(i..i, run);
+            },
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+// Checked for every day by the runner's tests: `S::parse` has to return an error for broken inputs instead of
+// panicking, and inputs it accepts have to be solved without panicking. Inputs are random text or small
+// generated inputs with a few edits. Parts get a short budget, days that check it stop early on slow inputs.
+pub fn check_robust<S: Generator>(runs: u64) {
+    for seed in 0..runs {
+        let mut rng = Rng::new(seed);
+        let input = if rng.chance(0.25) {
+            random_text(&mut rng, 64)
+        } else {
+            let size = rng.range(1..=S::DEFAULT_SIZE.clamp(1, 20) as i64) as usize;
+            let valid = S::generate(&mut rng, size);
+            mutate(&mut rng, &valid)
+        };
+        let Ok(parsed) = std::panic::catch_unwind(|| S::parse(&input)) else {
+            panic!("Parsing day {:02} panicked for seed {} on {:?}", S::DAY, seed, input);
+        };
+        let Ok(parsed) = parsed else { continue };
+        let budget = || Budget::new().with_timeout(PART_TIMEOUT);
+        let part1 = AssertUnwindSafe(|| with_budget(budget(), || { S::part1(&parsed); }));
+        if std::panic::catch_unwind(part1).is_err() {
+            panic!("Day {:02} part 1 panicked for seed {} on {:?}", S::DAY, seed, input);
+        }
+        let part2 = AssertUnwindSafe(|| with_budget(budget(), || { S::part2(&parsed); }));
+        if std::panic::catch_unwind(part2).is_err() {
+            panic!("Day {:02} part 2 panicked for seed {} on {:?}", S::DAY, seed, input);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng_is_reproducible() {
+        let a: Vec<_> = { let mut r = Rng::new(42); (0..10).map(|_| r.next_u64()).collect() };
+        let b: Vec<_> = { let mut r = Rng::new(42); (0..10).map(|_| r.next_u64()).collect() };
+        assert_eq!(a, b);
+        assert_ne!(Rng::new(1).next_u64(), Rng::new(2).next_u64());
+    }
+
+    #[test]
+    fn test_ranges() {
+        let mut r = Rng::new(7);
+        for _ in 0..1000 {
+            assert!((-3..=5).contains(&r.range(-3..=5)));
+            assert!(r.index(3) < 3);
+        }
+        assert_eq!(r.range(4..=4), 4);
+        let mut seen = [false; 6];
+        for _ in 0..1000 {
+            seen[r.range(0..=5) as usize] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+        let mut v: Vec<_> = (0..20).collect();
+        r.shuffle(&mut v);
+        v.sort();
+        assert_eq!(v, (0..20).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_mutate() {
+        let mutated = |seed| { let mut r = Rng::new(seed); (0..100).map(|_| mutate(&mut r, "abc\ndef")).collect::<Vec<_>>() };
+        assert_eq!(mutated(3), mutated(3));
+        assert!(mutated(3).iter().filter(|m| *m != "abc\ndef").count() > 90);
+        let mut r = Rng::new(3);
+        assert!((0..100).all(|_| random_text(&mut r, 10).chars().count() <= 10));
+    }
+
+    #[test]
+    fn test_lowercase_name() {
+        assert_eq!(lowercase_name(0), "a");
+        assert_eq!(lowercase_name(25), "z");
+        assert_eq!(lowercase_name(26), "aa");
+        assert_eq!(lowercase_name(27), "ab");
+        assert_eq!(lowercase_name(26 + 26 * 26), "aaa");
+    }
+}
@@ -0,0 +1,140 @@
+use crate::ParseError;
+use std::io::Read;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+pub const INPUT_USAGE: &str = "[PATH | - | --input-str INPUT]";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+    Inline(String),
+}
+
+impl InputSource {
+    // `-` reads stdin, `--input-str` takes the input itself, anything else is a path
+    pub fn from_args<I: Iterator<Item = String>>(mut args: I, default: InputSource) -> Result<InputSource, String> {
+        let source = match args.next().as_deref() {
+            None => default,
+            Some("-") => InputSource::Stdin,
+            Some("--input-str") => InputSource::Inline(args.next().ok_or("Missing input for '--input-str'")?),
+            Some(s) if s.starts_with("--") => return Err(format!("Unexpected argument '{}'", s)),
+            Some(path) => InputSource::File(path.into()),
+        };
+        if let Some(arg) = args.next() {
+            return Err(format!("Unexpected argument '{}'", arg));
+        }
+        Ok(source)
+    }
+
+    pub fn read(&self) -> std::io::Result<String> {
+        match self {
+            InputSource::Stdin => {
+                let mut s = String::new();
+                std::io::stdin().read_to_string(&mut s)?;
+                Ok(s)
+            },
+            InputSource::File(path) => std::fs::read_to_string(path),
+            InputSource::Inline(s) => Ok(s.clone()),
+        }
+    }
+
+    pub fn attach_to(&self, e: ParseError) -> ParseError {
+        match self {
+            InputSource::File(path) => e.with_file(path),
+            InputSource::Stdin | InputSource::Inline(_) => e,
+        }
+    }
+}
+
+impl std::fmt::Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Inline(_) => write!(f, "<inline>"),
+        }
+    }
+}
+
+// Input handling shared by the single-day binaries, errors are reported before returning the exit code
+pub fn input_from_args(default: InputSource) -> Result<(InputSource, String), ExitCode> {
+    input_and_flags_from_args(default, &[]).map(|(source, input, _)| (source, input))
+}
+
+// Splits the arguments into the ones in `flags` and the rest. The input after `--input-str` is never a flag.
+fn take_flags<I: Iterator<Item = String>>(mut args: I, flags: &[&str]) -> (Vec<String>, Vec<String>) {
+    let (mut given, mut rest) = (Vec::new(), Vec::new());
+    while let Some(arg) = args.next() {
+        if arg == "--input-str" {
+            rest.push(arg);
+            rest.extend(args.next());
+        } else if flags.contains(&arg.as_str()) {
+            given.push(arg);
+        } else {
+            rest.push(arg);
+        }
+    }
+    (given, rest)
+}
+
+// Like `input_from_args` for binaries with switches of their own, e.g. `--lenient` of day 01. Also returns
+// which of `flags` were given.
+pub fn input_and_flags_from_args(default: InputSource, flags: &[&str]) -> Result<(InputSource, String, Vec<String>), ExitCode> {
+    let (given, args) = take_flags(std::env::args().skip(1), flags);
+    let source = InputSource::from_args(args.into_iter(), default).map_err(|e| {
+        let program = std::env::args().next().unwrap_or_default();
+        let flags: String = flags.iter().map(|f| format!("[{}] ", f)).collect();
+        eprintln!("{}\n\nUsage: {} {}{}", e, program, flags, INPUT_USAGE);
+        ExitCode::from(2)
+    })?;
+    match source.read() {
+        Ok(input) => Ok((source, input, given)),
+        Err(e) => {
+            eprintln!("Error reading {}: {}", source, e);
+            Err(ExitCode::FAILURE)
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Result<InputSource, String> {
+        InputSource::from_args(s.split_whitespace().map(String::from), InputSource::File("input".into()))
+    }
+
+    #[test]
+    fn test_from_args() {
+        assert_eq!(parse(""), Ok(InputSource::File("input".into())));
+        assert_eq!(parse("-"), Ok(InputSource::Stdin));
+        assert_eq!(parse("foo/bar"), Ok(InputSource::File("foo/bar".into())));
+        assert_eq!(parse("--input-str abc"), Ok(InputSource::Inline("abc".into())));
+        assert!(parse("--input-str").is_err());
+        assert!(parse("--frobnicate").is_err());
+        assert!(parse("a b").is_err());
+    }
+
+    #[test]
+    fn test_take_flags() {
+        let args = |s: &str| s.split_whitespace().map(String::from).collect::<Vec<_>>();
+        let (given, rest) = take_flags(args("--lenient input.txt").into_iter(), &["--lenient", "--verbose"]);
+        assert_eq!((given, rest), (args("--lenient"), args("input.txt")));
+        let (given, rest) = take_flags(args("--input-str ()").into_iter(), &["--lenient"]);
+        assert_eq!((given, rest), (Vec::new(), args("--input-str ()")));
+        let (given, rest) = take_flags(args("--input-str --lenient").into_iter(), &["--lenient"]);
+        assert_eq!((given, rest), (Vec::new(), args("--input-str --lenient")));
+        let (given, rest) = take_flags(args("--lenient --input-str --lenient").into_iter(), &["--lenient"]);
+        assert_eq!((given, rest), (args("--lenient"), args("--input-str --lenient")));
+    }
+
+    #[test]
+    fn test_read() {
+        assert_eq!(InputSource::Inline("abc".into()).read().unwrap(), "abc");
+        assert!(InputSource::File("does/not/exist".into()).read().is_err());
+        assert_eq!(InputSource::File("x".into()).to_string(), "x");
+        assert_eq!(InputSource::Stdin.to_string(), "<stdin>");
+    }
+}
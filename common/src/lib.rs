@@ -0,0 +1,204 @@
+mod bench;
+mod budget;
+mod differential;
+mod error;
+mod example;
+mod generate;
+mod input;
+mod params;
+mod pattern;
+mod scoped;
+pub mod trace;
+pub mod visual;
+
+pub use bench::{bench, format_duration, solve_timed, Solved, Stats, Timings};
+pub use budget::{with_budget, Budget, Outcome};
+pub use differential::check_against_reference;
+pub use error::{parse_lines, parse_value, ParseError};
+pub use example::{check_examples, solve_example, Example, ExampleAnswers};
+pub use generate::{check_robust, lowercase_name, mutate, random_text, Generator, Rng, LOWERCASE};
+pub use input::{input_and_flags_from_args, input_from_args, InputSource, INPUT_USAGE};
+pub use params::{Configured, Param, Params};
+pub use pattern::{match_pattern, FieldCheck, FromLine};
+
+pub trait Solution {
+    const DAY: u8;
+    const NAME: &'static str;
+
+    type Input;
+    type Answer1: Answer;
+    type Answer2: Answer;
+
+    // Puzzle constants the runner can override, see `parse_with`
+    const PARAMS: &'static [Param] = &[];
+    const EXAMPLES: &'static [Example] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    // Days with parameters keep them in their input, `parse` uses the defaults
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, ParseError> {
+        let _ = params;
+        Self::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1;
+    fn part2(input: &Self::Input) -> Self::Answer2;
+
+    // Text picture of the solved puzzle for reports, e.g. a grid after the last step
+    fn picture(input: &Self::Input) -> Option<String> {
+        let _ = input;
+        None
+    }
+}
+
+pub trait Answer {
+    fn to_answer(&self) -> Option<String>;
+}
+
+macro_rules! impl_answer {
+    ($($t:ty),*) => {
+        $(impl Answer for $t {
+            fn to_answer(&self) -> Option<String> {
+                Some(self.to_string())
+            }
+        })*
+    };
+}
+
+impl_answer!(i32, i64, u16, usize, String);
+
+// For searches that may find nothing, unlike `NoAnswer` there is a puzzle to answer
+impl<T: Answer> Answer for Option<T> {
+    fn to_answer(&self) -> Option<String> {
+        match self {
+            Some(v) => v.to_answer(),
+            None => Some("none".into()),
+        }
+    }
+}
+
+// Day 25 has no second puzzle
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct NoAnswer;
+
+impl Answer for NoAnswer {
+    fn to_answer(&self) -> Option<String> {
+        None
+    }
+}
+
+// Answers to both parts as printed by the runners
+pub type Answers = (String, Option<String>);
+
+pub fn solve<S: Solution>(input: &str) -> Result<Answers, ParseError> {
+    let parsed = S::parse(input)?;
+    let answer1 = S::part1(&parsed).to_answer().expect("Part 1 always has an answer");
+    let answer2 = S::part2(&parsed).to_answer();
+    Ok((answer1, answer2))
+}
+
+pub fn picture<S: Solution>(input: &str, params: &Params) -> Result<Option<String>, ParseError> {
+    Ok(S::picture(&S::parse_with(input, params)?))
+}
+
+// Only solves the part asked for, `None` for part 2 of day 25 and parts that do not exist
+pub fn solve_part<S: Solution>(input: &str, params: &Params, part: u8) -> Result<Option<String>, ParseError> {
+    let parsed = S::parse_with(input, params)?;
+    Ok(match part {
+        1 => S::part1(&parsed).to_answer(),
+        2 => S::part2(&parsed).to_answer(),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+        const NAME: &'static str = "sum";
+        const PARAMS: &'static [Param] = &[Param::new("offset", 0, "Added to the sum")];
+        const EXAMPLES: &'static [Example] = &[
+            Example::new("1\n2\n3").part1("6"),
+            Example::new("1\n2").with_params(&[("offset", 10)]).part1("13"),
+        ];
+
+        type Input = Configured<Vec<i64>>;
+        type Answer1 = i64;
+        type Answer2 = NoAnswer;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            Self::parse_with(input, &Params::defaults(Self::PARAMS))
+        }
+
+        fn parse_with(input: &str, params: &Params) -> Result<Self::Input, ParseError> {
+            Ok(Configured { value: parse_lines(input, |l| parse_value(l, l))?, params: params.clone() })
+        }
+
+        fn part1(input: &Self::Input) -> i64 {
+            input.value.iter().sum::<i64>() + input.params.get("offset")
+        }
+
+        fn part2(_input: &Self::Input) -> NoAnswer {
+            NoAnswer
+        }
+    }
+
+    #[test]
+    fn test_answer() {
+        assert_eq!(42_i32.to_answer(), Some("42".into()));
+        assert_eq!(String::from("abc").to_answer(), Some("abc".into()));
+        assert_eq!(NoAnswer.to_answer(), None);
+        assert_eq!(Some(7_usize).to_answer(), Some("7".into()));
+        assert_eq!(None::<usize>.to_answer(), Some("none".into()));
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(solve::<Sum>("1\n2\n3"), Ok(("6".into(), None)));
+        assert_eq!(solve::<Sum>("1\nx\n3").unwrap_err().line, 2);
+    }
+
+    #[test]
+    fn test_picture() {
+        let params = Params::defaults(Sum::PARAMS);
+        assert_eq!(picture::<Sum>("1\n2", &params), Ok(None));
+        assert!(picture::<Sum>("x", &params).is_err());
+    }
+
+    #[test]
+    fn test_solve_part() {
+        let mut params = Params::defaults(Sum::PARAMS);
+        params.set("offset", 1).unwrap();
+        assert_eq!(solve_part::<Sum>("1\n2", &params, 1), Ok(Some("4".into())));
+        assert_eq!(solve_part::<Sum>("1\n2", &params, 2), Ok(None));
+        assert_eq!(solve_part::<Sum>("1\n2", &params, 3), Ok(None));
+        assert!(solve_part::<Sum>("x", &params, 1).is_err());
+    }
+
+    #[test]
+    fn test_examples() {
+        check_examples::<Sum>();
+        assert_eq!(solve_example::<Sum>(&Example::new("4")), Ok((None, None)));
+        assert_eq!(solve_example::<Sum>(&Example::new("4").part1("5")), Ok((Some("4".into()), None)));
+        assert!(solve_example::<Sum>(&Example::new("x").part1("5")).is_err());
+    }
+
+    #[test]
+    fn test_bench() {
+        let params = Params::defaults(Sum::PARAMS);
+        let timings = bench::<Sum>("1\n2\n3", &params, 3).unwrap();
+        assert!(timings.parse.min <= timings.parse.median);
+        assert_eq!(bench::<Sum>("1\nx\n3", &params, 3).unwrap_err().line, 2);
+    }
+
+    #[test]
+    fn test_solve_timed() {
+        let params = Params::defaults(Sum::PARAMS);
+        assert_eq!(solve_timed::<Sum>("1\n2\n3", &params).unwrap().answers, ("6".into(), None));
+        assert_eq!(solve_timed::<Sum>("x", &params).unwrap_err().line, 1);
+    }
+}
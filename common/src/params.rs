@@ -0,0 +1,109 @@
+// Puzzle constant that can be changed from the runner, e.g. the length of the race in day 14
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: i64,
+    pub min: i64,
+    pub max: i64,
+    pub description: &'static str,
+}
+
+impl Param {
+    pub const fn new(name: &'static str, default: i64, description: &'static str) -> Param {
+        Param { name, default, min: i64::MIN, max: i64::MAX, description }
+    }
+
+    pub const fn range(mut self, min: i64, max: i64) -> Param {
+        self.min = min;
+        self.max = max;
+        self
+    }
+}
+
+// Values for the parameters a day declares, starting out at their defaults
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Params {
+    specs: &'static [Param],
+    values: Vec<i64>,
+}
+
+impl Params {
+    pub fn defaults(specs: &'static [Param]) -> Params {
+        Params { specs, values: specs.iter().map(|p| p.default).collect() }
+    }
+
+    pub fn specs(&self) -> &'static [Param] {
+        self.specs
+    }
+
+    pub fn set(&mut self, name: &str, value: i64) -> Result<(), String> {
+        let Some(i) = self.specs.iter().position(|p| p.name == name) else {
+            let names: Vec<_> = self.specs.iter().map(|p| p.name).collect();
+            return Err(if names.is_empty() {
+                format!("Unknown parameter '{}', there are none", name)
+            } else {
+                format!("Unknown parameter '{}', expected one of {}", name, names.join(", "))
+            });
+        };
+        let Param { min, max, .. } = self.specs[i];
+        if value < min || value > max {
+            return Err(format!("Parameter '{}' must be between {} and {}", name, min, max));
+        }
+        self.values[i] = value;
+        Ok(())
+    }
+
+    // Asking for a parameter the day did not declare is a bug in the day
+    pub fn get(&self, name: &str) -> i64 {
+        let i = self.specs.iter().position(|p| p.name == name).unwrap_or_else(|| panic!("Undeclared parameter '{}'", name));
+        self.values[i]
+    }
+
+    // The declared range has to fit `T`
+    pub fn get_as<T: TryFrom<i64>>(&self, name: &str) -> T {
+        T::try_from(self.get(name)).unwrap_or_else(|_| panic!("Parameter '{}' does not fit its type", name))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&'static Param, i64)> + '_ {
+        self.specs.iter().zip(self.values.iter().copied())
+    }
+}
+
+// Parsed input of a day together with the parameters it is solved with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Configured<T> {
+    pub value: T,
+    pub params: Params,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PARAMS: &[Param] = &[
+        Param::new("seconds", 2503, "Length of the race").range(0, u16::MAX as i64),
+        Param::new("offset", -1, "Anything goes"),
+    ];
+
+    #[test]
+    fn test_params() {
+        let mut params = Params::defaults(PARAMS);
+        assert_eq!((params.get("seconds"), params.get("offset")), (2503, -1));
+        params.set("seconds", 1000).unwrap();
+        params.set("offset", i64::MIN).unwrap();
+        assert_eq!(params.get_as::<u16>("seconds"), 1000);
+        assert_eq!(params.iter().map(|(p, v)| (p.name, v)).collect::<Vec<_>>(), vec![("seconds", 1000), ("offset", i64::MIN)]);
+
+        assert_eq!(params.set("seconds", -1), Err("Parameter 'seconds' must be between 0 and 65535".into()));
+        assert!(params.set("seconds", 65536).is_err());
+        assert_eq!(params.set("second", 1), Err("Unknown parameter 'second', expected one of seconds, offset".into()));
+        assert_eq!(Params::defaults(&[]).set("second", 1), Err("Unknown parameter 'second', there are none".into()));
+        assert_eq!(params.get("seconds"), 1000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Undeclared parameter 'steps'")]
+    fn test_undeclared_param() {
+        Params::defaults(PARAMS).get("steps");
+    }
+}
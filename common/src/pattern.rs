@@ -0,0 +1,181 @@
+use crate::ParseError;
+
+// Types that are parsed from a single line of input, see `line_struct!`
+pub trait FromLine: Sized {
+    fn from_line(line: &str) -> Result<Self, ParseError>;
+}
+
+pub type FieldCheck = fn(&str, &str) -> bool;
+
+// Splits `line` along a pattern where every `{}` stands for one non-empty field, e.g. `"{} to {} = {}"`.
+// Fields are as short as possible, but a split is only accepted when `checks[i](line, field)` holds for
+// every field. If no split passes the checks, the shortest split ignoring the checks is returned so the
+// caller can report which field is wrong.
+pub fn match_pattern<'a>(line: &'a str, pattern: &str, checks: &[FieldCheck]) -> Result<Vec<&'a str>, ParseError> {
+    let literals: Vec<&str> = pattern.split("{}").collect();
+    assert_eq!(literals.len() - 1, checks.len(), "Pattern '{}' needs one check per field", pattern);
+    let expected = || ParseError::new(line, line, format!("Expected '{}'", pattern));
+    let rest = line.strip_prefix(literals[0]).ok_or_else(expected)?;
+    let mut fields = Vec::with_capacity(checks.len());
+    if split(line, rest, &literals[1..], Some(checks), &mut fields) || split(line, rest, &literals[1..], None, &mut fields) {
+        Ok(fields)
+    } else {
+        Err(expected())
+    }
+}
+
+// `literals[i]` is the text following field i
+fn split<'a>(line: &str, rest: &'a str, literals: &[&str], checks: Option<&[FieldCheck]>, fields: &mut Vec<&'a str>) -> bool {
+    let Some((&literal, literals_left)) = literals.split_first() else {
+        return rest.is_empty();
+    };
+    let index = fields.len();
+    let accept = |field: &str| !field.is_empty() && checks.is_none_or(|c| c[index](line, field));
+    if literals_left.is_empty() {
+        return match rest.strip_suffix(literal) {
+            Some(field) if accept(field) => {
+                fields.push(field);
+                true
+            },
+            _ => false,
+        };
+    }
+    for (pos, _) in rest.match_indices(literal) {
+        let field = &rest[..pos];
+        if !accept(field) {
+            continue;
+        }
+        fields.push(field);
+        if split(line, &rest[pos + literal.len()..], literals_left, checks, fields) {
+            return true;
+        }
+        fields.pop();
+    }
+    false
+}
+
+// Defines a struct together with a `FromLine` impl that fills its fields, in order, from the `{}`
+// placeholders of a pattern. Fields are parsed with `FromStr` unless a parser function taking the line and
+// the field text is given after `=`, and `where check => message` rejects parsed values.
+//
+//     line_struct! {
+//         #[derive(Debug)]
+//         pub struct Connection = "{} to {} = {}" {
+//             pub from: String,
+//             pub to: String,
+//             pub distance: i64 where |d| *d > 0 => "Distance must be positive",
+//         }
+//     }
+#[macro_export]
+macro_rules! line_struct {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident = $pattern:literal {
+            $($field_vis:vis $field:ident : $ty:ty $(= $parser:path)? $(where $check:expr => $message:expr)?),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            $($field_vis $field: $ty),*
+        }
+
+        impl $crate::FromLine for $name {
+            fn from_line(line: &str) -> Result<Self, $crate::ParseError> {
+                let checks: &[$crate::FieldCheck] = &[$(|l, s| $crate::line_field!(l, s, $ty $(, $parser)?).is_ok()),*];
+                let mut fields = $crate::match_pattern(line, $pattern, checks)?.into_iter();
+                $(
+                    let part = fields.next().expect("One part per field");
+                    let $field: $ty = $crate::line_field!(line, part, $ty $(, $parser)?)?;
+                    $(
+                        let check: fn(&$ty) -> bool = $check;
+                        if !check(&$field) {
+                            return Err($crate::ParseError::new(line, part, $message));
+                        }
+                    )?
+                )*
+                Ok($name { $($field),* })
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! line_field {
+    ($line:expr, $part:expr, $ty:ty) => {
+        $crate::parse_value::<$ty>($line, $part)
+    };
+    ($line:expr, $part:expr, $ty:ty, $parser:path) => {
+        $parser($line, $part)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_value;
+
+    line_struct! {
+        #[derive(Debug, PartialEq, Eq)]
+        struct Connection = "{} to {} = {}" {
+            from: String,
+            to: String,
+            distance: i64 where |d| *d > 0 => "Distance must be positive",
+        }
+    }
+
+    fn parse_sign(line: &str, part: &str) -> Result<i64, ParseError> {
+        match part {
+            "gain" => Ok(1),
+            "lose" => Ok(-1),
+            _ => Err(ParseError::new(line, part, "Expected 'gain' or 'lose'")),
+        }
+    }
+
+    line_struct! {
+        #[derive(Debug)]
+        struct Change = "{} would {} {}." {
+            name: String,
+            sign: i64 = parse_sign,
+            amount: u32,
+        }
+    }
+
+    #[test]
+    fn test_match_pattern() {
+        let any: FieldCheck = |_, _| true;
+        assert_eq!(match_pattern("a to b = 3", "{} to {} = {}", &[any; 3]), Ok(vec!["a", "b", "3"]));
+        assert_eq!(match_pattern("Sue 1: x", "Sue {}: {}", &[any; 2]), Ok(vec!["1", "x"]));
+        assert_eq!(match_pattern("x", "x", &[]), Ok(vec![]));
+        assert!(match_pattern("a to b", "{} to {} = {}", &[any; 3]).is_err());
+        assert!(match_pattern("a to  = 3", "{} to {} = {}", &[any; 3]).is_err());
+        assert!(match_pattern("a to b = 3.", "{} to {} = {}.!", &[any; 3]).is_err());
+
+        // The shortest fields would make the first field "turn", the checks push it to "turn on"
+        let number: FieldCheck = |l, s| parse_value::<u32>(l, s).is_ok();
+        assert_eq!(match_pattern("turn on 0", "{} {}", &[any, number]), Ok(vec!["turn on", "0"]));
+        assert_eq!(match_pattern("turn on x", "{} {}", &[any, number]), Ok(vec!["turn", "on x"]));
+    }
+
+    #[test]
+    fn test_line_struct() {
+        let c = Connection::from_line("London to Dublin = 464").unwrap();
+        assert_eq!(c, Connection { from: "London".into(), to: "Dublin".into(), distance: 464 });
+        assert_eq!(Connection::from_line("London to New York = 3").unwrap().to, "New York");
+
+        let e = Connection::from_line("London - Dublin = 464").unwrap_err();
+        assert_eq!((e.column, e.message.as_str()), (1, "Expected '{} to {} = {}'"));
+        let e = Connection::from_line("London to Dublin = x").unwrap_err();
+        assert_eq!((e.column, e.message.as_str()), (20, "Invalid value 'x'"));
+        let e = Connection::from_line("London to Dublin = 0").unwrap_err();
+        assert_eq!((e.column, e.message.as_str()), (20, "Distance must be positive"));
+    }
+
+    #[test]
+    fn test_line_struct_parser() {
+        let c = Change::from_line("Alice would lose 3.").unwrap();
+        assert_eq!((c.name.as_str(), c.sign, c.amount), ("Alice", -1, 3));
+        let e = Change::from_line("Alice would win 3.").unwrap_err();
+        assert_eq!((e.column, e.message.as_str()), (13, "Expected 'gain' or 'lose'"));
+    }
+}
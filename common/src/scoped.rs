@@ -0,0 +1,53 @@
+use std::cell::RefCell;
+use std::thread::LocalKey;
+
+// Tracers, viewers and budgets live in thread locals, so days solved in parallel only reach their own
+pub(crate) type Slot<T> = LocalKey<RefCell<Option<T>>>;
+
+// Runs `f` with `value` in `slot`, the previous value is restored afterwards, also on panic
+pub(crate) fn with_scoped<T: 'static, R>(slot: &'static Slot<T>, value: T, f: impl FnOnce() -> R) -> R {
+    struct Restore<T: 'static>(&'static Slot<T>, Option<T>);
+
+    impl<T> Drop for Restore<T> {
+        fn drop(&mut self) {
+            self.0.with(|s| *s.borrow_mut() = self.1.take());
+        }
+    }
+
+    let previous = slot.with(|s| s.borrow_mut().replace(value));
+    let _restore = Restore(slot, previous);
+    f()
+}
+
+// A slot that is borrowed is in use by its own value, e.g. a tracer that emits events itself
+pub(crate) fn is_set<T>(slot: &'static Slot<T>) -> bool {
+    slot.with(|s| s.try_borrow().map_or(true, |s| s.is_some()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    thread_local! {
+        static SLOT: RefCell<Option<i32>> = const { RefCell::new(None) };
+    }
+
+    fn get() -> Option<i32> {
+        SLOT.with(|s| *s.borrow())
+    }
+
+    #[test]
+    fn test_with_scoped() {
+        assert!(!is_set(&SLOT));
+        let inner = with_scoped(&SLOT, 1, || {
+            assert_eq!(get(), Some(1));
+            with_scoped(&SLOT, 2, get)
+        });
+        assert_eq!(inner, Some(2));
+        assert!(!is_set(&SLOT));
+
+        let result = std::panic::catch_unwind(|| with_scoped(&SLOT, 3, || panic!("Hook failed")));
+        assert!(result.is_err());
+        assert_eq!(get(), None);
+    }
+}
@@ -0,0 +1,88 @@
+use crate::scoped::{is_set, with_scoped};
+use std::cell::RefCell;
+
+// Structured event emitted by a solver through `trace!`, fields keep their order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    pub kind: &'static str,
+    pub fields: Vec<(&'static str, String)>,
+}
+
+impl std::fmt::Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.kind)?;
+        for (key, value) in &self.fields {
+            write!(f, " {}={}", key, value)?;
+        }
+        Ok(())
+    }
+}
+
+type Tracer = Box<dyn FnMut(&Event)>;
+
+thread_local! {
+    static TRACER: RefCell<Option<Tracer>> = const { RefCell::new(None) };
+}
+
+pub fn enabled() -> bool {
+    is_set(&TRACER)
+}
+
+pub fn emit(event: &Event) {
+    TRACER.with(|t| {
+        if let Some(tracer) = t.borrow_mut().as_mut() {
+            tracer(event);
+        }
+    });
+}
+
+// Runs `f` with every event passed to `tracer`, the previous tracer is restored afterwards, also on panic
+pub fn with_tracer<R>(tracer: impl FnMut(&Event) + 'static, f: impl FnOnce() -> R) -> R {
+    with_scoped(&TRACER, Box::new(tracer), f)
+}
+
+// `trace!("step", ip = vm.ip, a = vm.a)` emits an event when a tracer is installed. The values are only
+// formatted (with `Display`) when tracing is enabled.
+#[macro_export]
+macro_rules! trace {
+    ($kind:literal $(, $key:ident = $value:expr)* $(,)?) => {
+        if $crate::trace::enabled() {
+            $crate::trace::emit(&$crate::trace::Event {
+                kind: $kind,
+                fields: vec![$((stringify!($key), $value.to_string())),*],
+            });
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_with_tracer() {
+        trace!("ignored", n = 1);
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let sink = events.clone();
+        let answer = with_tracer(move |e| sink.borrow_mut().push(e.to_string()), || {
+            for i in 0..3 {
+                trace!("step", i = i, square = i * i);
+            }
+            trace!("done");
+            42
+        });
+        assert_eq!(answer, 42);
+        assert!(!enabled());
+        trace!("ignored", n = 2);
+        assert_eq!(*events.borrow(), vec!["step i=0 square=0", "step i=1 square=1", "step i=2 square=4", "done"]);
+    }
+
+    #[test]
+    fn test_tracer_restored_on_panic() {
+        let result = std::panic::catch_unwind(|| with_tracer(|_| {}, || panic!("Solver failed")));
+        assert!(result.is_err());
+        assert!(!enabled());
+        assert!(TRACER.with(|t| t.borrow().is_none()));
+    }
+}
@@ -0,0 +1,63 @@
+use crate::scoped::{is_set, with_scoped};
+use std::cell::RefCell;
+
+// One picture of a solver's state, e.g. a grid after a step of the simulation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub title: String,
+    pub lines: Vec<String>,
+}
+
+// Size in characters that pictures of large grids are scaled down to
+pub const MAX_WIDTH: usize = 100;
+pub const MAX_HEIGHT: usize = 50;
+
+type Viewer = Box<dyn FnMut(&Frame)>;
+
+thread_local! {
+    static VIEWER: RefCell<Option<Viewer>> = const { RefCell::new(None) };
+}
+
+pub fn enabled() -> bool {
+    is_set(&VIEWER)
+}
+
+// Passes a frame to the viewer, `frame` is only called when there is one
+pub fn show(frame: impl FnOnce() -> Frame) {
+    if !enabled() {
+        return;
+    }
+    let frame = frame();
+    VIEWER.with(|v| {
+        if let Some(viewer) = v.borrow_mut().as_mut() {
+            viewer(&frame);
+        }
+    });
+}
+
+// Runs `f` with every frame passed to `viewer`, the previous viewer is restored afterwards, also on panic
+pub fn with_viewer<R>(viewer: impl FnMut(&Frame) + 'static, f: impl FnOnce() -> R) -> R {
+    with_scoped(&VIEWER, Box::new(viewer), f)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_with_viewer() {
+        show(|| panic!("Frames are not built without a viewer"));
+        let titles = Rc::new(RefCell::new(Vec::new()));
+        let sink = titles.clone();
+        let answer = with_viewer(move |f| sink.borrow_mut().push(f.title.clone()), || {
+            for i in 0..3 {
+                show(|| Frame { title: format!("step {}", i), lines: vec!["#.".into()] });
+            }
+            42
+        });
+        assert_eq!(answer, 42);
+        assert!(!enabled());
+        assert_eq!(*titles.borrow(), vec!["step 0", "step 1", "step 2"]);
+    }
+}
@@ -0,0 +1,196 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+#[derive(Debug)]
+pub enum FetchError {
+    MissingSession,
+    Status { url: String, status: u16 },
+    Transport { url: String, message: String },
+    EmptyInput { url: String },
+    Io { path: PathBuf, error: std::io::Error },
+}
+
+impl std::fmt::Display for FetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FetchError::MissingSession => write!(f, "No session token, set AOC_SESSION or use --session-file"),
+            FetchError::Status { url, status } => write!(f, "Downloading {} failed with status {}", url, status),
+            FetchError::Transport { url, message } => write!(f, "Downloading {} failed: {}", url, message),
+            FetchError::EmptyInput { url } => write!(f, "Downloading {} returned an empty input", url),
+            FetchError::Io { path, error } => write!(f, "Error writing {}: {}", path.display(), error),
+        }
+    }
+}
+
+// Inputs are stored as `<cache dir>/<year>/<day>.txt`
+pub fn cache_path(cache_dir: &Path, year: u16, day: u8) -> PathBuf {
+    cache_dir.join(year.to_string()).join(format!("{:02}.txt", day))
+}
+
+// Session cookie value from the AOC_SESSION environment variable
+pub fn session_from_env() -> Option<String> {
+    std::env::var("AOC_SESSION").ok().map(|s| s.trim().to_string()).filter(|s| !s.is_empty())
+}
+
+pub struct Fetcher {
+    base_url: String,
+    cache_dir: PathBuf,
+    session: Option<String>,
+    agent: ureq::Agent,
+}
+
+impl Fetcher {
+    pub fn new(base_url: &str, cache_dir: impl Into<PathBuf>) -> Fetcher {
+        Fetcher {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            cache_dir: cache_dir.into(),
+            session: None,
+            agent: ureq::AgentBuilder::new().timeout(Duration::from_secs(30)).build(),
+        }
+    }
+
+    pub fn with_session(mut self, session: impl Into<String>) -> Self {
+        self.session = Some(session.into());
+        self
+    }
+
+    pub fn url(&self, year: u16, day: u8) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, year, day)
+    }
+
+    pub fn cache_path(&self, year: u16, day: u8) -> PathBuf {
+        cache_path(&self.cache_dir, year, day)
+    }
+
+    // Path of the cached input and whether it had to be downloaded first. A cached input is never
+    // downloaded again, failed downloads leave nothing behind in the cache.
+    pub fn fetch(&self, year: u16, day: u8) -> Result<(PathBuf, bool), FetchError> {
+        let path = self.cache_path(year, day);
+        if path.is_file() {
+            return Ok((path, false));
+        }
+        let input = self.download(year, day)?;
+        write_atomically(&path, &input).map_err(|error| FetchError::Io { path: path.clone(), error })?;
+        Ok((path, true))
+    }
+
+    fn download(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        let session = self.session.as_ref().ok_or(FetchError::MissingSession)?;
+        let url = self.url(year, day);
+        let response = self.agent.get(&url)
+            .set("Cookie", &format!("session={}", session))
+            .set("User-Agent", "aoc2015 input fetcher")
+            .call()
+            .map_err(|e| match e {
+                ureq::Error::Status(status, _) => FetchError::Status { url: url.clone(), status },
+                ureq::Error::Transport(t) => FetchError::Transport { url: url.clone(), message: t.to_string() },
+            })?;
+        let input = response.into_string().map_err(|e| FetchError::Transport { url: url.clone(), message: e.to_string() })?;
+        if input.is_empty() {
+            return Err(FetchError::EmptyInput { url });
+        }
+        Ok(input)
+    }
+}
+
+// Writes to a temporary file first, so an interrupted download never looks like a cached input
+fn write_atomically(path: &Path, contents: &str) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let tmp = path.with_extension("part");
+    std::fs::write(&tmp, contents)?;
+    std::fs::rename(&tmp, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+
+    // Serves `/2015/day/<day>/input` for the days in `inputs` and 404 for anything else, every request
+    // is recorded so tests can check what was sent
+    fn stub_server(inputs: &'static [(u8, &'static str)]) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let log = requests.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut request = String::new();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                loop {
+                    let mut line = String::new();
+                    if reader.read_line(&mut line).unwrap() == 0 || line == "\r\n" {
+                        break;
+                    }
+                    request += &line;
+                }
+                let path = request.split_whitespace().nth(1).unwrap_or_default().to_string();
+                log.lock().unwrap().push(request);
+                let body = inputs.iter().find(|(day, _)| path == format!("/2015/day/{}/input", day)).map(|(_, body)| *body);
+                let response = match body {
+                    Some(body) => format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", body.len(), body),
+                    None => "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_string(),
+                };
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+        (base_url, requests)
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc_fetch_{}_{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_fetch_and_cache() {
+        let (base_url, requests) = stub_server(&[(7, "123 -> x\n")]);
+        let dir = temp_dir("cache");
+        let fetcher = Fetcher::new(&base_url, &dir).with_session("secret");
+
+        let (path, downloaded) = fetcher.fetch(2015, 7).unwrap();
+        assert!(downloaded);
+        assert_eq!(path, dir.join("2015").join("07.txt"));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "123 -> x\n");
+        {
+            let requests = requests.lock().unwrap();
+            assert_eq!(requests.len(), 1);
+            assert!(requests[0].starts_with("GET /2015/day/7/input "), "{}", requests[0]);
+            assert!(requests[0].contains("session=secret"), "{}", requests[0]);
+        }
+
+        // Cached inputs are used as they are, even with a different server or without a session
+        assert_eq!(Fetcher::new("http://127.0.0.1:9", &dir).fetch(2015, 7).unwrap(), (path, false));
+        assert_eq!(requests.lock().unwrap().len(), 1);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_failures_are_not_cached() {
+        let (base_url, requests) = stub_server(&[(1, "")]);
+        let dir = temp_dir("failures");
+        let fetcher = Fetcher::new(&base_url, &dir).with_session("secret");
+        assert!(matches!(fetcher.fetch(2015, 2), Err(FetchError::Status { status: 404, .. })));
+        assert!(matches!(fetcher.fetch(2015, 1), Err(FetchError::EmptyInput { .. })));
+        assert!(matches!(Fetcher::new(&base_url, &dir).fetch(2015, 2), Err(FetchError::MissingSession)));
+        assert_eq!(requests.lock().unwrap().len(), 2);
+        assert!(!fetcher.cache_path(2015, 1).exists());
+        assert!(!fetcher.cache_path(2015, 2).exists());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_url() {
+        let fetcher = Fetcher::new("https://example.com/", "cache");
+        assert_eq!(fetcher.url(2015, 7), "https://example.com/2015/day/7/input");
+        assert_eq!(fetcher.cache_path(2015, 7), Path::new("cache/2015/07.txt"));
+    }
+}
@@ -0,0 +1,47 @@
+#![no_main]
+
+use aoc_common::{with_budget, Budget, Solution};
+use libfuzzer_sys::fuzz_target;
+use std::time::Duration;
+
+// Inputs that parse are solved too, days that can take long stop when their budget runs out
+fn check<S: Solution>(input: &str) {
+    let Ok(parsed) = S::parse(input) else { return };
+    let budget = || Budget::new().with_timeout(Duration::from_millis(100));
+    with_budget(budget(), || S::part1(&parsed));
+    with_budget(budget(), || S::part2(&parsed));
+}
+
+// The first byte picks the day, the rest is its input
+fuzz_target!(|data: &[u8]| {
+    let Some((&day, input)) = data.split_first() else { return };
+    let Ok(input) = std::str::from_utf8(input) else { return };
+    match day % 25 + 1 {
+        1 => check::<not_quite_lisp::Day01>(input),
+        2 => check::<i_was_told_there_would_be_no_math::Day02>(input),
+        3 => check::<perfectly_spherical_houses_in_a_vacuum::Day03>(input),
+        4 => check::<the_ideal_stocking_stuffer::Day04>(input),
+        5 => check::<doesnt_he_have_internelves_for_this::Day05>(input),
+        6 => check::<probably_a_fire_hazard::Day06>(input),
+        7 => check::<some_assembly_required::Day07>(input),
+        8 => check::<matchsticks::Day08>(input),
+        9 => check::<all_in_a_single_night::Day09>(input),
+        10 => check::<elves_look_elves_say::Day10>(input),
+        11 => check::<corporate_policy::Day11>(input),
+        12 => check::<jsabacus_framework_io::Day12>(input),
+        13 => check::<knights_of_the_dinner_table::Day13>(input),
+        14 => check::<reindeer_olympics::Day14>(input),
+        15 => check::<science_for_hungry_people::Day15>(input),
+        16 => check::<aunt_sue::Day16>(input),
+        17 => check::<no_such_thing_as_too_much::Day17>(input),
+        18 => check::<like_a_gif_for_your_yard::Day18>(input),
+        19 => check::<medicine_for_rudolph::Day19>(input),
+        20 => check::<infinite_elves_and_infinite_houses::Day20>(input),
+        21 => check::<rpg_simulator_20xx::Day21>(input),
+        22 => check::<wizard_simulator_20xx::Day22>(input),
+        23 => check::<opening_the_turing_lock::Day23>(input),
+        24 => check::<it_hangs_in_the_balance::Day24>(input),
+        25 => check::<let_it_snow::Day25>(input),
+        _ => unreachable!(),
+    }
+});
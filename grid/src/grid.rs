@@ -0,0 +1,297 @@
+use crate::{Point, NEIGHBORS, ORTHOGONAL};
+use aoc_common::ParseError;
+use std::ops::{Index, IndexMut};
+
+// Cells that have a single character representation, used for parsing and `Display`
+pub trait Tile: Sized {
+    fn from_char(c: char) -> Option<Self>;
+    fn to_char(&self) -> char;
+}
+
+impl Tile for bool {
+    fn from_char(c: char) -> Option<bool> {
+        match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        if *self { '#' } else { '.' }
+    }
+}
+
+// Row major rectangle of cells, indexed with `(x, y)` where (0, 0) is the top left corner
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T> {
+        Grid { cells: vec![value; width * height], width, height }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(width: usize, height: usize, mut f: F) -> Grid<T> {
+        let cells = (0..height).flat_map(|y| (0..width).map(move |x| (x, y))).map(|(x, y)| f(x, y)).collect();
+        Grid { cells, width, height }
+    }
+
+    // Parses a rectangle of characters, every line must have the same width
+    pub fn parse_with<F: FnMut(char) -> Option<T>>(input: &str, mut f: F) -> Result<Grid<T>, ParseError> {
+        let lines: Vec<_> = input.lines().collect();
+        let width = lines.first().map_or(0, |l| l.chars().count());
+        if width == 0 {
+            return Err(ParseError::new(input, input, "Empty grid"));
+        }
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (iy, &l) in lines.iter().enumerate() {
+            if l.chars().count() != width {
+                return Err(ParseError::new(l, l, format!("Expected line of width {}", width)).on_line(iy + 1));
+            }
+            for (ix, c) in l.char_indices() {
+                match f(c) {
+                    Some(v) => cells.push(v),
+                    None => return Err(ParseError::new(l, &l[ix..], format!("Unexpected character: {}", c)).on_line(iy + 1)),
+                }
+            }
+        }
+        Ok(Grid { cells, width, height: lines.len() })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        (0..self.width as i64).contains(&p.x) && (0..self.height as i64).contains(&p.y)
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.contains(p).then(|| &self.cells[p.y as usize * self.width + p.x as usize])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        if !self.contains(p) {
+            return None;
+        }
+        Some(&mut self.cells[p.y as usize * self.width + p.x as usize])
+    }
+
+    // Treats the grid as a torus, so coordinates outside of it wrap around
+    pub fn get_wrapping(&self, p: Point) -> &T {
+        &self[self.wrap(p)]
+    }
+
+    pub fn wrap(&self, p: Point) -> (usize, usize) {
+        assert!(!self.cells.is_empty(), "Can not wrap in an empty grid");
+        (p.x.rem_euclid(self.width as i64) as usize, p.y.rem_euclid(self.height as i64) as usize)
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, value: T) {
+        self[(x, y)] = value;
+    }
+
+    // The up to 8 cells around (x, y) that are inside the grid
+    pub fn neighbors(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offsets(x, y, &NEIGHBORS)
+    }
+
+    // The up to 4 cells sharing an edge with (x, y) that are inside the grid
+    pub fn orthogonal_neighbors(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offsets(x, y, &ORTHOGONAL)
+    }
+
+    pub fn wrapping_neighbors(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let p = Point::new(x as i64, y as i64);
+        NEIGHBORS.iter().map(move |&d| self.wrap(p + d))
+    }
+
+    fn offsets<'a>(&'a self, x: usize, y: usize, offsets: &'static [Point]) -> impl Iterator<Item = (usize, usize)> + 'a {
+        let p = Point::new(x as i64, y as i64);
+        offsets.iter()
+            .map(move |&d| p + d)
+            .filter(|&n| self.contains(n))
+            .map(|n| (n.x as usize, n.y as usize))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn rows(&self) -> std::slice::Chunks<'_, T> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid { cells: self.cells.iter().map(f).collect(), width: self.width, height: self.height }
+    }
+
+    // Text picture of at most `max_width` x `max_height` characters for visualizations. Every character
+    // stands for a block of cells and gets darker with the average `level` (0 to 1) of the block.
+    pub fn shaded<F: Fn(&T) -> f64>(&self, max_width: usize, max_height: usize, level: F) -> Vec<String> {
+        const SHADES: &[u8] = b" .:-=+*#%@";
+        let block_width = self.width.div_ceil(max_width.max(1)).max(1);
+        let block_height = self.height.div_ceil(max_height.max(1)).max(1);
+        (0..self.height).step_by(block_height).map(|y0| {
+            (0..self.width).step_by(block_width).map(|x0| {
+                let (x1, y1) = ((x0 + block_width).min(self.width), (y0 + block_height).min(self.height));
+                let total: f64 = (y0..y1).flat_map(|y| (x0..x1).map(move |x| (x, y))).map(|p| level(&self[p]).clamp(0.0, 1.0)).sum();
+                let average = total / ((x1 - x0) * (y1 - y0)) as f64;
+                SHADES[(average * (SHADES.len() - 1) as f64).round() as usize] as char
+            }).collect()
+        }).collect()
+    }
+}
+
+impl<T: Tile> Grid<T> {
+    pub fn parse(input: &str) -> Result<Grid<T>, ParseError> {
+        Grid::parse_with(input, T::from_char)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(x < self.width && y < self.height, "({}, {}) outside of {}x{} grid", x, y, self.width, self.height);
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(x < self.width && y < self.height, "({}, {}) outside of {}x{} grid", x, y, self.width, self.height);
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+impl<T: Tile> std::fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use std::fmt::Write;
+        for row in self.rows() {
+            for cell in row {
+                f.write_char(cell.to_char())?;
+            }
+            f.write_char('\n')?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Cell {
+        Wall,
+        Open,
+        Start,
+    }
+
+    impl Tile for Cell {
+        fn from_char(c: char) -> Option<Cell> {
+            match c {
+                '#' => Some(Cell::Wall),
+                '.' => Some(Cell::Open),
+                'S' => Some(Cell::Start),
+                _ => None,
+            }
+        }
+
+        fn to_char(&self) -> char {
+            match self {
+                Cell::Wall => '#',
+                Cell::Open => '.',
+                Cell::Start => 'S',
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let g = Grid::<bool>::parse("#.#\n...\n").unwrap();
+        assert_eq!((g.width(), g.height()), (3, 2));
+        assert!(g[(2, 0)]);
+        assert!(!g[(2, 1)]);
+        assert_eq!(g.to_string(), "#.#\n...\n");
+
+        let g = Grid::<Cell>::parse("#S\n.#").unwrap();
+        assert_eq!(g[(1, 0)], Cell::Start);
+        assert_eq!(g.to_string(), "#S\n.#\n");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let e = Grid::<bool>::parse("#.#\n.#\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+        let e = Grid::<bool>::parse("#.#\n.o.\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 2));
+        assert!(Grid::<bool>::parse("").is_err());
+    }
+
+    #[test]
+    fn test_access() {
+        let mut g = Grid::from_fn(4, 3, |x, y| x + 10 * y);
+        assert_eq!(g[(3, 2)], 23);
+        assert_eq!(g.get(Point::new(1, 2)), Some(&21));
+        assert_eq!(g.get(Point::new(4, 0)), None);
+        assert_eq!(g.get(Point::new(0, -1)), None);
+        assert_eq!(g.get_wrapping(Point::new(4, -1)), &20);
+        assert_eq!(g.get_wrapping(Point::new(-1, 5)), &23);
+        *g.get_mut(Point::new(0, 0)).unwrap() = 99;
+        g.set(1, 0, 98);
+        assert_eq!(g.iter().take(2).copied().collect::<Vec<_>>(), vec![99, 98]);
+        assert_eq!(g.positions().nth(5), Some((1, 1)));
+        assert_eq!(g.map(|&v| v % 2 == 1).iter().filter(|&&b| b).count(), 6);
+    }
+
+    #[test]
+    fn test_shaded() {
+        let g = Grid::from_fn(4, 3, |x, y| x < 2 && y < 2);
+        let on = |&b: &bool| if b { 1.0 } else { 0.0 };
+        assert_eq!(g.shaded(4, 3, on), vec!["@@  ", "@@  ", "    "]);
+        assert_eq!(g.shaded(2, 2, on), vec!["@ ", "  "]);
+        assert_eq!(g.shaded(1, 1, on), vec!["-"]);
+        assert!(Grid::new(0, 0, false).shaded(10, 10, on).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds() {
+        let g = Grid::new(3, 3, 0);
+        let _ = g[(3, 0)];
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let g = Grid::new(3, 3, 0);
+        assert_eq!(g.neighbors(1, 1).count(), 8);
+        assert_eq!(g.neighbors(0, 0).collect::<Vec<_>>(), vec![(1, 0), (0, 1), (1, 1)]);
+        assert_eq!(g.neighbors(2, 1).count(), 5);
+        assert_eq!(g.orthogonal_neighbors(0, 0).collect::<Vec<_>>(), vec![(1, 0), (0, 1)]);
+        assert_eq!(g.orthogonal_neighbors(1, 1).count(), 4);
+        let mut wrapped: Vec<_> = g.wrapping_neighbors(0, 0).collect();
+        wrapped.sort();
+        assert_eq!(wrapped, vec![(0, 1), (0, 2), (1, 0), (1, 1), (1, 2), (2, 0), (2, 1), (2, 2)]);
+    }
+}
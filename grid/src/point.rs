@@ -0,0 +1,114 @@
+use std::ops::{Add, AddAssign};
+
+// Screen coordinates: x grows to the right, y grows downwards
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const fn new(x: i64, y: i64) -> Point {
+        Point { x, y }
+    }
+
+    pub fn step(self, d: Direction) -> Point {
+        self + d.offset()
+    }
+
+    pub fn manhattan(self, other: Point) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    // Arrow characters as used by the puzzles, `^` `v` `<` `>`
+    pub fn from_char(c: char) -> Option<Direction> {
+        match c {
+            '^' => Some(Direction::Up),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            '>' => Some(Direction::Right),
+            _ => None,
+        }
+    }
+
+    pub fn offset(self) -> Point {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+            Direction::Right => Point::new(1, 0),
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        self.turn_left().turn_left().turn_left()
+    }
+}
+
+pub const ORTHOGONAL: [Point; 4] = [Point::new(0, -1), Point::new(1, 0), Point::new(0, 1), Point::new(-1, 0)];
+
+pub const NEIGHBORS: [Point; 8] = [
+    Point::new(-1, -1), Point::new(0, -1), Point::new(1, -1),
+    Point::new(-1, 0), Point::new(1, 0),
+    Point::new(-1, 1), Point::new(0, 1), Point::new(1, 1),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_step() {
+        let mut p = Point::default();
+        for (c, expected) in [('^', (0, -1)), ('>', (1, -1)), ('v', (1, 0)), ('<', (0, 0))] {
+            p = p.step(Direction::from_char(c).unwrap());
+            assert_eq!(p, Point::new(expected.0, expected.1));
+        }
+        assert_eq!(Direction::from_char('x'), None);
+        assert_eq!(Point::new(1, -2).manhattan(Point::new(-2, 2)), 7);
+    }
+
+    #[test]
+    fn test_turn() {
+        for d in Direction::ALL {
+            assert_eq!(d.turn_left().turn_right(), d);
+            assert_eq!(d.turn_left().turn_left(), d.turn_right().turn_right());
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+    }
+}
@@ -0,0 +1,140 @@
+use aoc_common::visual::Frame;
+use std::io::{IsTerminal, Write};
+use std::time::{Duration, Instant};
+
+pub const DEFAULT_FPS: u32 = 30;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    // Every frame replaces the previous one on the screen
+    Ansi,
+    // Frames are written one after the other without escape codes or delays, for pipes and files
+    Plain,
+}
+
+pub struct Renderer<W: Write> {
+    out: W,
+    mode: Mode,
+    interval: Duration,
+    next_frame: Option<Instant>,
+    started: bool,
+}
+
+impl Renderer<std::io::Stdout> {
+    // ANSI output when stdout is a terminal, plain text otherwise
+    pub fn stdout(fps: u32) -> Self {
+        let out = std::io::stdout();
+        let mode = if out.is_terminal() { Mode::Ansi } else { Mode::Plain };
+        Renderer::new(out, mode, fps)
+    }
+}
+
+impl<W: Write> Renderer<W> {
+    // An `fps` of 0 draws frames as fast as they come
+    pub fn new(out: W, mode: Mode, fps: u32) -> Self {
+        let interval = if fps == 0 { Duration::ZERO } else { Duration::from_secs(1) / fps };
+        Renderer { out, mode, interval, next_frame: None, started: false }
+    }
+
+    pub fn draw(&mut self, frame: &Frame) -> std::io::Result<()> {
+        match self.mode {
+            Mode::Ansi => {
+                self.wait_for_next_frame();
+                if !self.started {
+                    // Hide the cursor and clear the screen once, later frames only overwrite
+                    write!(self.out, "\x1b[?25l\x1b[2J")?;
+                    self.started = true;
+                }
+                writeln!(self.out, "\x1b[H{}\x1b[K", frame.title)?;
+                for line in &frame.lines {
+                    writeln!(self.out, "{}\x1b[K", line)?;
+                }
+                write!(self.out, "\x1b[J")?;
+            },
+            Mode::Plain => {
+                writeln!(self.out, "{}", frame.title)?;
+                for line in &frame.lines {
+                    writeln!(self.out, "{}", line.trim_end())?;
+                }
+                writeln!(self.out)?;
+            },
+        }
+        self.out.flush()
+    }
+
+    // Frames are at least 1/fps apart, the solver waits for the terminal
+    fn wait_for_next_frame(&mut self) {
+        if let Some(next) = self.next_frame {
+            let now = Instant::now();
+            if next > now {
+                std::thread::sleep(next - now);
+            }
+        }
+        self.next_frame = Some(Instant::now() + self.interval);
+    }
+
+    // Shows the cursor again, the last frame stays on the screen
+    pub fn finish(&mut self) -> std::io::Result<()> {
+        if self.started {
+            write!(self.out, "\x1b[?25h")?;
+            self.started = false;
+        }
+        self.out.flush()
+    }
+}
+
+impl<W: Write> Drop for Renderer<W> {
+    fn drop(&mut self) {
+        let _ = self.finish();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(title: &str, lines: &[&str]) -> Frame {
+        Frame { title: title.into(), lines: lines.iter().map(|l| l.to_string()).collect() }
+    }
+
+    #[test]
+    fn test_plain() {
+        let mut out = Vec::new();
+        {
+            let mut r = Renderer::new(&mut out, Mode::Plain, DEFAULT_FPS);
+            r.draw(&frame("Step 1", &["#. ", ".# "])).unwrap();
+            r.draw(&frame("Step 2", &["##"])).unwrap();
+        }
+        assert_eq!(String::from_utf8(out).unwrap(), "Step 1\n#.\n.#\n\nStep 2\n##\n\n");
+    }
+
+    #[test]
+    fn test_ansi() {
+        let mut out = Vec::new();
+        {
+            let mut r = Renderer::new(&mut out, Mode::Ansi, 0);
+            r.draw(&frame("Step 1", &["#."])).unwrap();
+            r.draw(&frame("Step 2", &[".#"])).unwrap();
+        }
+        assert_eq!(String::from_utf8(out).unwrap(), concat!(
+            "\x1b[?25l\x1b[2J", "\x1b[HStep 1\x1b[K\n#.\x1b[K\n\x1b[J",
+            "\x1b[HStep 2\x1b[K\n.#\x1b[K\n\x1b[J", "\x1b[?25h"));
+    }
+
+    #[test]
+    fn test_frame_rate() {
+        let mut r = Renderer::new(std::io::sink(), Mode::Ansi, 50);
+        let start = Instant::now();
+        for i in 0..4 {
+            r.draw(&frame(&i.to_string(), &[])).unwrap();
+        }
+        assert!(start.elapsed() >= Duration::from_millis(60), "{:?}", start.elapsed());
+
+        let mut r = Renderer::new(std::io::sink(), Mode::Plain, 1);
+        let start = Instant::now();
+        for i in 0..4 {
+            r.draw(&frame(&i.to_string(), &[])).unwrap();
+        }
+        assert!(start.elapsed() < Duration::from_millis(500));
+    }
+}
@@ -0,0 +1,241 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::Arc;
+use std::time::Duration;
+
+pub const DEFAULT_PORT: u16 = 2015;
+
+// Puzzle inputs are a few tens of kilobytes, generated ones can be larger
+pub const MAX_BODY: usize = 16 << 20;
+const MAX_HEADER_LINE: u64 = 8 << 10;
+const MAX_HEADERS: usize = 100;
+const READ_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    // `name=value` pairs after the `?`, taken as they are without percent decoding
+    pub query: Vec<(String, String)>,
+    pub body: String,
+}
+
+// Bodies are always JSON
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    pub fn json(status: u16, body: impl ToString) -> Response {
+        Response { status, body: body.to_string() }
+    }
+
+    // Only for the fixed messages of this crate, they need no escaping
+    fn error(status: u16, message: &str) -> Response {
+        Response::json(status, format!("{{\"error\":\"{}\"}}", message))
+    }
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        413 => "Payload Too Large",
+        431 => "Request Header Fields Too Large",
+        500 => "Internal Server Error",
+        501 => "Not Implemented",
+        503 => "Service Unavailable",
+        _ => "",
+    }
+}
+
+fn read_line(reader: &mut impl BufRead) -> Result<String, Response> {
+    let mut line = String::new();
+    reader.by_ref().take(MAX_HEADER_LINE).read_line(&mut line).map_err(|_| Response::error(400, "Malformed request"))?;
+    if !line.ends_with('\n') {
+        return Err(if line.len() as u64 == MAX_HEADER_LINE {
+            Response::error(431, "Header line too long")
+        } else {
+            Response::error(400, "Incomplete request")
+        });
+    }
+    Ok(line.trim_end_matches(['\r', '\n']).to_string())
+}
+
+// Errors come back as the response to send instead
+pub fn read_request(reader: &mut impl BufRead) -> Result<Request, Response> {
+    let request_line = read_line(reader)?;
+    let mut parts = request_line.split(' ');
+    let (Some(method), Some(target), Some(version), None) = (parts.next(), parts.next(), parts.next(), parts.next()) else {
+        return Err(Response::error(400, "Malformed request line"));
+    };
+    if !version.starts_with("HTTP/1.") {
+        return Err(Response::error(400, "Unsupported HTTP version"));
+    }
+
+    let mut length = 0;
+    for i in 0.. {
+        let line = read_line(reader)?;
+        if line.is_empty() {
+            break;
+        }
+        if i == MAX_HEADERS {
+            return Err(Response::error(431, "Too many headers"));
+        }
+        let (name, value) = line.split_once(':').ok_or_else(|| Response::error(400, "Malformed header"))?;
+        if name.eq_ignore_ascii_case("content-length") {
+            length = value.trim().parse().map_err(|_| Response::error(400, "Invalid Content-Length"))?;
+        } else if name.eq_ignore_ascii_case("transfer-encoding") {
+            return Err(Response::error(501, "Only bodies with a Content-Length are supported"));
+        }
+    }
+    if length > MAX_BODY {
+        return Err(Response::error(413, "Body too large"));
+    }
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).map_err(|_| Response::error(400, "Incomplete body"))?;
+    let body = String::from_utf8(body).map_err(|_| Response::error(400, "Body is not UTF-8"))?;
+
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let query = query.split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (name.to_string(), value.to_string())
+        })
+        .collect();
+    Ok(Request { method: method.to_string(), path: path.to_string(), query, body })
+}
+
+pub fn write_response(out: &mut impl Write, response: &Response) -> std::io::Result<()> {
+    write!(out, "HTTP/1.1 {} {}\r\n", response.status, reason(response.status))?;
+    write!(out, "Content-Type: application/json\r\nContent-Length: {}\r\n", response.body.len())?;
+    write!(out, "Connection: close\r\n\r\n{}", response.body)?;
+    out.flush()
+}
+
+// One request per connection, a panicking handler answers with a 500
+fn handle_connection(stream: TcpStream, handler: &(dyn Fn(&Request) -> Response + Sync)) -> std::io::Result<()> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let response = match read_request(&mut BufReader::new(&stream)) {
+        Ok(request) => catch_unwind(AssertUnwindSafe(|| handler(&request)))
+            .unwrap_or_else(|_| Response::error(500, "Solver panicked")),
+        Err(response) => response,
+    };
+    write_response(&mut &stream, &response)
+}
+
+pub struct Server {
+    listener: TcpListener,
+}
+
+impl Server {
+    // Only reachable from this machine, port 0 picks a free one
+    pub fn bind(port: u16) -> std::io::Result<Server> {
+        Ok(Server { listener: TcpListener::bind((Ipv4Addr::LOCALHOST, port))? })
+    }
+
+    pub fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    // Every connection gets its own thread, runs until the process is stopped
+    pub fn serve(self, handler: impl Fn(&Request) -> Response + Send + Sync + 'static) {
+        let handler = Arc::new(handler);
+        for stream in self.listener.incoming() {
+            let stream = match stream {
+                Ok(s) => s,
+                Err(e) => {
+                    eprintln!("Error accepting connection: {}", e);
+                    continue;
+                }
+            };
+            let handler = handler.clone();
+            std::thread::spawn(move || {
+                if let Err(e) = handle_connection(stream, &*handler) {
+                    eprintln!("Error answering request: {}", e);
+                }
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read(s: impl AsRef<[u8]>) -> Result<Request, Response> {
+        read_request(&mut s.as_ref())
+    }
+
+    #[test]
+    fn test_read_request() {
+        let request = read("POST /day/07/part/1?a=1&b HTTP/1.1\r\nHost: localhost\r\ncontent-length: 6\r\n\r\n1 -> bextra").unwrap();
+        assert_eq!(request, Request {
+            method: "POST".into(),
+            path: "/day/07/part/1".into(),
+            query: vec![("a".into(), "1".into()), ("b".into(), "".into())],
+            body: "1 -> b".into(),
+        });
+        let request = read("GET / HTTP/1.0\n\n").unwrap();
+        assert_eq!((request.path.as_str(), request.query.len(), request.body.as_str()), ("/", 0, ""));
+    }
+
+    #[test]
+    fn test_read_request_errors() {
+        let status = |s: &[u8]| read(s).map(|_| ()).map_err(|r| r.status);
+        assert_eq!(status(b""), Err(400));
+        assert_eq!(status(b"GET /\r\n\r\n"), Err(400));
+        assert_eq!(status(b"GET / SMTP\r\n\r\n"), Err(400));
+        assert_eq!(status(b"GET / HTTP/1.1\r\nHost localhost\r\n\r\n"), Err(400));
+        assert_eq!(status(b"GET / HTTP/1.1\r\nHost: localhost\r\n"), Err(400));
+        assert_eq!(status(b"POST / HTTP/1.1\r\nContent-Length: x\r\n\r\n"), Err(400));
+        assert_eq!(status(b"POST / HTTP/1.1\r\nContent-Length: 10\r\n\r\nshort"), Err(400));
+        assert_eq!(status(b"POST / HTTP/1.1\r\nContent-Length: 2\r\n\r\n\xff\xfe"), Err(400));
+        assert_eq!(status(format!("POST / HTTP/1.1\r\nContent-Length: {}\r\n\r\n", MAX_BODY + 1).as_bytes()), Err(413));
+        assert_eq!(status(b"POST / HTTP/1.1\r\nTransfer-Encoding: chunked\r\n\r\n"), Err(501));
+        assert_eq!(status(format!("GET /{} HTTP/1.1\r\n\r\n", "a".repeat(MAX_HEADER_LINE as usize)).as_bytes()), Err(431));
+        assert_eq!(status(format!("GET / HTTP/1.1\r\n{}\r\n", "A: b\r\n".repeat(MAX_HEADERS + 1)).as_bytes()), Err(431));
+    }
+
+    #[test]
+    fn test_write_response() {
+        let mut out = Vec::new();
+        write_response(&mut out, &Response::json(404, r#"{"error":"x"}"#)).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), concat!(
+            "HTTP/1.1 404 Not Found\r\nContent-Type: application/json\r\nContent-Length: 13\r\n",
+            "Connection: close\r\n\r\n{\"error\":\"x\"}"));
+    }
+
+    fn send(addr: SocketAddr, request: &str) -> String {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream.write_all(request.as_bytes()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    #[test]
+    fn test_serve() {
+        let server = Server::bind(0).unwrap();
+        let addr = server.local_addr().unwrap();
+        assert!(addr.ip().is_loopback());
+        std::thread::spawn(move || server.serve(|request| {
+            if request.path == "/panic" {
+                panic!("Handler failed");
+            }
+            Response::json(200, format!("{{\"length\":{}}}", request.body.len()))
+        }));
+
+        let response = send(addr, "POST /echo HTTP/1.1\r\nContent-Length: 3\r\n\r\nabc");
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"), "{}", response);
+        assert!(response.ends_with("\r\n\r\n{\"length\":3}"), "{}", response);
+        assert!(send(addr, "POST /panic HTTP/1.1\r\n\r\n").starts_with("HTTP/1.1 500 "));
+        assert!(send(addr, "nonsense\r\n\r\n").starts_with("HTTP/1.1 400 "));
+    }
+}
@@ -0,0 +1,183 @@
+// Brute force search for the best ordering of a small set of nodes, e.g. a route through every city
+// or a seating order around a table
+
+use aoc_common::{Budget, Outcome};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shape {
+    // Visits every node once, start and end are free
+    Path,
+    // Visits every node once and returns to the first one
+    Cycle,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Objective {
+    Minimize,
+    Maximize,
+}
+
+impl Objective {
+    fn better(self, a: i64, b: i64) -> bool {
+        match self {
+            Objective::Minimize => a < b,
+            Objective::Maximize => a > b,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tour {
+    pub order: Vec<usize>,
+    pub cost: i64,
+}
+
+// `weight(a, b)` is the cost of going from node `a` to node `b`, or None when that step is not allowed.
+// Returns None when there are no nodes or no ordering uses only allowed steps.
+pub fn best_tour<F>(nodes: usize, shape: Shape, objective: Objective, weight: F) -> Option<Tour>
+where
+    F: Fn(usize, usize) -> Option<i64>,
+{
+    best_tour_within(nodes, shape, objective, &Budget::new(), weight).map(|o| o.done().expect("Unlimited budget"))
+}
+
+// Like `best_tour`, but gives up with the best tour so far when the budget runs out. A step is a
+// partial tour tried.
+pub fn best_tour_within<F>(nodes: usize, shape: Shape, objective: Objective, budget: &Budget, weight: F) -> Option<Outcome<Tour>>
+where
+    F: Fn(usize, usize) -> Option<i64>,
+{
+    if nodes == 0 {
+        return None;
+    }
+    let mut search = Search {
+        shape, objective, weight, budget, order: Vec::with_capacity(nodes), used: vec![false; nodes], best: None, steps: 0, stopped: false,
+    };
+    // Every rotation of a cycle is the same tour, so cycles always start at node 0
+    let starts = match shape {
+        Shape::Path => nodes,
+        Shape::Cycle => 1,
+    };
+    for start in 0..starts {
+        search.used[start] = true;
+        search.order.push(start);
+        search.visit(0);
+        search.order.pop();
+        search.used[start] = false;
+    }
+    if search.stopped {
+        return Some(Outcome::Stopped { partial: search.best, steps: search.steps });
+    }
+    search.best.map(Outcome::Done)
+}
+
+struct Search<'a, F> {
+    shape: Shape,
+    objective: Objective,
+    weight: F,
+    budget: &'a Budget,
+    order: Vec<usize>,
+    used: Vec<bool>,
+    best: Option<Tour>,
+    steps: u64,
+    stopped: bool,
+}
+
+impl<F: Fn(usize, usize) -> Option<i64>> Search<'_, F> {
+    fn visit(&mut self, cost: i64) {
+        if self.stopped {
+            return;
+        }
+        self.steps += 1;
+        if self.steps.is_multiple_of(0x10000) && self.budget.is_exhausted() {
+            self.stopped = true;
+            return;
+        }
+        let last = *self.order.last().expect("Search starts with one node");
+        if self.order.len() == self.used.len() {
+            let cost = match self.shape {
+                Shape::Cycle if self.order.len() > 1 => match (self.weight)(last, self.order[0]) {
+                    Some(w) => cost + w,
+                    None => return,
+                },
+                _ => cost,
+            };
+            if self.best.as_ref().is_none_or(|b| self.objective.better(cost, b.cost)) {
+                self.best = Some(Tour { order: self.order.clone(), cost });
+            }
+            return;
+        }
+        for next in 0..self.used.len() {
+            if self.used[next] {
+                continue;
+            }
+            let Some(w) = (self.weight)(last, next) else { continue };
+            self.used[next] = true;
+            self.order.push(next);
+            self.visit(cost + w);
+            self.order.pop();
+            self.used[next] = false;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Distances between London, Dublin and Belfast
+    const CITIES: [[i64; 3]; 3] = [[0, 464, 518], [464, 0, 141], [518, 141, 0]];
+
+    fn cities(a: usize, b: usize) -> Option<i64> {
+        Some(CITIES[a][b])
+    }
+
+    #[test]
+    fn test_path() {
+        let shortest = best_tour(3, Shape::Path, Objective::Minimize, cities).unwrap();
+        assert_eq!(shortest, Tour { order: vec![0, 1, 2], cost: 605 });
+        let longest = best_tour(3, Shape::Path, Objective::Maximize, cities).unwrap();
+        assert_eq!(longest, Tour { order: vec![1, 0, 2], cost: 982 });
+    }
+
+    #[test]
+    fn test_cycle() {
+        let t = best_tour(3, Shape::Cycle, Objective::Minimize, cities).unwrap();
+        assert_eq!(t.cost, 464 + 141 + 518);
+        assert_eq!(t.order[0], 0);
+
+        // Going from a to b costs a - b, so only the direction of the cycle matters
+        let t = best_tour(4, Shape::Cycle, Objective::Maximize, |a, b| Some(a as i64 * 10 - b as i64)).unwrap();
+        assert_eq!(t.cost, (0..4).map(|i| i * 10 - i).sum::<i64>());
+    }
+
+    #[test]
+    fn test_forbidden_steps() {
+        // Only steps to the next node are allowed, so there is a single path
+        let t = best_tour(4, Shape::Path, Objective::Maximize, |a, b| (b == a + 1).then_some(1)).unwrap();
+        assert_eq!(t, Tour { order: vec![0, 1, 2, 3], cost: 3 });
+        assert_eq!(best_tour(4, Shape::Cycle, Objective::Maximize, |a, b| (b == a + 1).then_some(1)), None);
+    }
+
+    #[test]
+    fn test_small() {
+        assert_eq!(best_tour(0, Shape::Path, Objective::Minimize, cities), None);
+        assert_eq!(best_tour(1, Shape::Cycle, Objective::Minimize, cities), Some(Tour { order: vec![0], cost: 0 }));
+        assert_eq!(best_tour(2, Shape::Cycle, Objective::Minimize, cities).unwrap().cost, 2 * 464);
+    }
+
+    #[test]
+    fn test_budget() {
+        let cancelled = Budget::new();
+        cancelled.cancel();
+        let weight = |a: usize, b: usize| Some((a * 7 + b * 3) as i64 % 11);
+        let Some(Outcome::Stopped { partial: Some(partial), steps }) = best_tour_within(12, Shape::Path, Objective::Minimize, &cancelled, weight) else {
+            panic!("Search is not stopped");
+        };
+        assert_eq!(steps, 0x10000);
+        assert_eq!(partial.order.len(), 12);
+
+        let done = best_tour_within(3, Shape::Path, Objective::Minimize, &cancelled, cities);
+        assert_eq!(done, Some(Outcome::Done(Tour { order: vec![0, 1, 2], cost: 605 })));
+    }
+}